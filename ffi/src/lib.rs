@@ -0,0 +1,9 @@
+//! Shared library exporting the C ABI of `adventofcode2017::ffi`, declared in
+//! `include/knot_hash.h`.
+//!
+//! It is a crate of its own as a `cdylib` cannot be built without `std`, while the main crate
+//! can.
+
+extern crate adventofcode2017;
+
+pub use adventofcode2017::ffi::{knot_hash_bytes, knot_hash_hex};
@@ -0,0 +1,47 @@
+/* Generated with cbindgen from src/ffi.rs, see cbindgen.toml. Do not edit by hand. */
+
+#ifndef KNOT_HASH_H
+#define KNOT_HASH_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Writes the 16 bytes of the knot hash of the `len` bytes at `data` to `out`.
+ *
+ * Returns 0 on success, and -1 without writing anything when `out` is null, or `data` is null
+ * while `len` is not 0.
+ *
+ * # Safety
+ *
+ * `data` must point to `len` readable bytes, or may be null when `len` is 0. `out` must point
+ * to 16 writable bytes which do not overlap them.
+ */
+int knot_hash_bytes(const uint8_t *data, size_t len, uint8_t *out);
+
+/**
+ * Writes the knot hash of the `len` bytes at `data` to `out` as 32 lowercase hexadecimal
+ * digits followed by a NUL.
+ *
+ * Returns 0 on success, and -1 without writing anything when `out` is null, or `data` is null
+ * while `len` is not 0.
+ *
+ * # Safety
+ *
+ * `data` must point to `len` readable bytes, or may be null when `len` is 0. `out` must point
+ * to a buffer of at least 33 writable chars which does not overlap them.
+ */
+int knot_hash_hex(const uint8_t *data, size_t len, char *out);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* KNOT_HASH_H */
@@ -0,0 +1,233 @@
+use failure::Error;
+use output::millis;
+use registry::{Day, Part};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// What is being timed: parsing the input is measured apart from solving each part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Step {
+    Parse,
+    Solve(Part),
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Step::Parse => f.pad("parse"),
+            Step::Solve(part) => part.fmt(f),
+        }
+    }
+}
+
+impl FromStr for Step {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Step, Error> {
+        match s {
+            "parse" => Ok(Step::Parse),
+            part => Ok(Step::Solve(part.parse()?)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timing {
+    pub day: u8,
+    pub step: Step,
+    pub samples: Vec<Duration>,
+}
+
+impl Timing {
+    pub fn min(&self) -> Duration {
+        self.samples.iter().min().cloned().unwrap_or_default()
+    }
+
+    pub fn median(&self) -> Duration {
+        let mut samples = self.samples.clone();
+        samples.sort();
+
+        match samples.len() {
+            0 => Duration::default(),
+            n if n % 2 == 1 => samples[n / 2],
+            n => (samples[n / 2 - 1] + samples[n / 2]) / 2,
+        }
+    }
+}
+
+/// Runs `day` on `input` `runs` times, timing the parsing and each of `parts` separately.
+pub fn measure(day: &Day, input: &str, parts: &[Part], runs: usize) -> Result<Vec<Timing>, Error> {
+    let mut parse = Vec::with_capacity(runs);
+    let mut solve = vec![Vec::with_capacity(runs); parts.len()];
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = day.parse(input)?;
+        parse.push(start.elapsed());
+
+        for (samples, &part) in solve.iter_mut().zip(parts) {
+            let start = Instant::now();
+            day.solve(part, &parsed)?;
+            samples.push(start.elapsed());
+        }
+    }
+
+    let mut timings = vec![Timing {
+        day: day.day,
+        step: Step::Parse,
+        samples: parse,
+    }];
+
+    timings.extend(parts.iter().zip(solve).map(|(&part, samples)| Timing {
+        day: day.day,
+        step: Step::Solve(part),
+        samples,
+    }));
+
+    Ok(timings)
+}
+
+/// Median time in milliseconds of each step of each day, as saved by a previous run.
+pub type Baseline = BTreeMap<(u8, Step), f64>;
+
+const BASELINE_HEADER: &str = "day\tstep\tmin_ms\tmedian_ms";
+
+pub fn save_baseline<P: AsRef<Path>>(path: P, timings: &[Timing]) -> Result<(), Error> {
+    let path = path.as_ref();
+    let mut file = File::create(path).map_err(|err| format_err!("{}: {}", path.display(), err))?;
+
+    writeln!(file, "{}", BASELINE_HEADER)?;
+    for timing in timings {
+        writeln!(
+            file,
+            "{}\t{}\t{:.3}\t{:.3}",
+            timing.day,
+            timing.step,
+            millis(timing.min()),
+            millis(timing.median())
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn read_baseline<P: AsRef<Path>>(path: P) -> Result<Baseline, Error> {
+    let path = path.as_ref();
+    let mut baseline = String::new();
+    File::open(path)
+        .and_then(|mut file| file.read_to_string(&mut baseline))
+        .map_err(|err| format_err!("{}: {}", path.display(), err))?;
+
+    parse_baseline(&baseline).map_err(|err| format_err!("{}: {}", path.display(), err))
+}
+
+pub fn parse_baseline(input: &str) -> Result<Baseline, Error> {
+    let mut baseline = Baseline::new();
+
+    for (i, line) in input.lines().enumerate() {
+        if line == BASELINE_HEADER || line.trim().is_empty() {
+            continue;
+        }
+
+        let fields: Vec<_> = line.split('\t').collect();
+        if fields.len() != 4 {
+            return Err(format_err!("line {}: expected 4 fields: {:?}", i + 1, line));
+        }
+
+        let day = fields[0]
+            .parse()
+            .map_err(|_| format_err!("line {}: invalid day: {:?}", i + 1, fields[0]))?;
+        let step = fields[1].parse()?;
+        let median = fields[3]
+            .parse()
+            .map_err(|_| format_err!("line {}: invalid time: {:?}", i + 1, fields[3]))?;
+
+        baseline.insert((day, step), median);
+    }
+
+    Ok(baseline)
+}
+
+/// Relative change of the median of `timing` compared to `baseline`, `0.1` meaning 10% slower.
+pub fn change(timing: &Timing, baseline: &Baseline) -> Option<f64> {
+    match baseline.get(&(timing.day, timing.step)) {
+        Some(&median) if median > 0.0 => Some(millis(timing.median()) / median - 1.0),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(samples: &[u64]) -> Timing {
+        Timing {
+            day: 1,
+            step: Step::Solve(Part::A),
+            samples: samples.iter().map(|&ms| Duration::from_millis(ms)).collect(),
+        }
+    }
+
+    #[test]
+    fn test_min_median() {
+        assert_eq!(Duration::from_millis(1), timing(&[3, 1, 2]).min());
+        assert_eq!(Duration::from_millis(2), timing(&[3, 1, 2]).median());
+        assert_eq!(Duration::from_millis(25), timing(&[40, 10, 20, 30]).median());
+        assert_eq!(Duration::default(), timing(&[]).median());
+    }
+
+    #[cfg(feature = "day1")]
+    #[test]
+    fn test_measure() {
+        let day = ::registry::get(1).unwrap();
+        let timings = measure(&day, "1212", &[Part::A, Part::B], 3).unwrap();
+
+        assert_eq!(3, timings.len());
+        assert_eq!(Step::Parse, timings[0].step);
+        assert_eq!(Step::Solve(Part::B), timings[2].step);
+        assert!(timings.iter().all(|timing| timing.samples.len() == 3));
+    }
+
+    #[cfg(feature = "day13")]
+    #[test]
+    fn test_measure_parse_error() {
+        let day = ::registry::get(13).unwrap();
+
+        // The parts only see the scanners, so a bad layer fails before any of them runs
+        assert!(measure(&day, "0: 3\n1: 2\n4: 4\n6: 4", &[Part::A, Part::B], 2).is_ok());
+        assert_eq!(
+            ::error::Error::parse(13, 2, 4, "expected a range of at least 1").with_source("1: 0"),
+            measure(&day, "0: 3\n1: 0", &[Part::A, Part::B], 2)
+                .unwrap_err()
+                .downcast()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_baseline() {
+        let baseline = parse_baseline(
+            "day\tstep\tmin_ms\tmedian_ms
+15\tparse\t0.001\t0.002
+15\ta\t540.000\t550.500",
+        ).unwrap();
+
+        assert_eq!(Some(&0.002), baseline.get(&(15, Step::Parse)));
+        assert_eq!(Some(&550.5), baseline.get(&(15, Step::Solve(Part::A))));
+        assert!(parse_baseline("15\ta\t1").is_err());
+        assert!(parse_baseline("15\tc\t1\t1").is_err());
+    }
+
+    #[test]
+    fn test_change() {
+        let baseline = parse_baseline("1\ta\t1\t2").unwrap();
+
+        assert_eq!(Some(0.5), change(&timing(&[3]), &baseline));
+        assert_eq!(None, change(&timing(&[3]), &Baseline::new()));
+    }
+}
@@ -0,0 +1,461 @@
+extern crate adventofcode2017;
+#[macro_use]
+extern crate failure;
+
+use adventofcode2017::bench::{self, Timing};
+use adventofcode2017::debugger;
+use adventofcode2017::output::{self, Format};
+use adventofcode2017::registry::{self, Day, Part};
+use adventofcode2017::trace::{CsvTrace, Profiler};
+use adventofcode2017::verify::{self, Verdict};
+use adventofcode2017::{input, runner};
+use failure::Error;
+use std::env;
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::string::String;
+use std::time::Instant;
+
+const USAGE: &str = "Usage:
+    aoc run <day> [--part <a|b>] [--input <path|->] [--format <text|json|tsv>]
+    aoc run --all [--part <a|b>] [--format <text|json|tsv>] [--jobs <n>]
+    aoc verify <day> [--part <a|b>] [--input <path|->] [--answers <path>]
+    aoc verify --all [--part <a|b>] [--answers <path>]
+    aoc bench <day> [--part <a|b>] [--input <path|->] [--runs <n>] [--save <path>] [--baseline <path>]
+    aoc bench --all [--part <a|b>] [--runs <n>] [--save <path>] [--baseline <path>]
+    aoc debug <18|23> [--part <a|b>] [--input <path>]
+    aoc profile <18|23> [--part <a|b>] [--input <path|->] [--steps <n>] [--trace <path>]
+        [--csv <path>] [--loops-csv <path>]
+
+debug steps through the assembly of days 18 and 23, reading commands such as step, break,
+watch or continue from the standard input: type help for the list.
+
+profile runs the assembly of days 18 and 23 until it ends, part a of day 18 ending once it
+recovers a frequency, or for a number of steps, then prints how many times each instruction ran
+and the hottest loops. --trace writes every instruction run and the registers it changed as CSV,
+--csv the count of each instruction and --loops-csv the loops.";
+
+fn main() {
+    if let Err(ref err) = run() {
+        eprintln!("error: {}", err);
+        ::std::process::exit(1);
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        Some("run") => run_days(&parse_options("run", &args[1..])?),
+        Some("verify") => verify_days(&parse_options("verify", &args[1..])?),
+        Some("bench") => bench_days(&parse_options("bench", &args[1..])?),
+        Some("debug") => debug_day(&parse_options("debug", &args[1..])?),
+        Some("profile") => profile_day(&parse_options("profile", &args[1..])?),
+        Some("help") | Some("--help") | Some("-h") => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        Some(command) => Err(format_err!("Unknown command: {}\n{}", command, USAGE)),
+        None => Err(format_err!("Missing command\n{}", USAGE)),
+    }
+}
+
+#[derive(Debug, Default)]
+struct Options {
+    day: Option<u8>,
+    all: bool,
+    part: Option<Part>,
+    input: Option<String>,
+    format: Format,
+    jobs: Option<usize>,
+    answers: Option<String>,
+    runs: Option<usize>,
+    save: Option<String>,
+    baseline: Option<String>,
+    steps: Option<usize>,
+    trace: Option<String>,
+    csv: Option<String>,
+    loops_csv: Option<String>,
+}
+
+fn parse_options(command: &str, args: &[String]) -> Result<Options, Error> {
+    let mut options = Options::default();
+    let mut it = args.iter();
+
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--all" => options.all = true,
+            "--part" => {
+                let part = it.next()
+                    .ok_or_else(|| format_err!("Missing value for --part"))?;
+                options.part = Some(part.parse()?);
+            }
+            "--input" => {
+                let path = it.next()
+                    .ok_or_else(|| format_err!("Missing value for --input"))?;
+                options.input = Some(path.clone());
+            }
+            "--format" if command == "run" => {
+                let format = it.next()
+                    .ok_or_else(|| format_err!("Missing value for --format"))?;
+                options.format = format.parse()?;
+            }
+            "--jobs" if command == "run" => {
+                let jobs = it.next()
+                    .ok_or_else(|| format_err!("Missing value for --jobs"))?;
+                options.jobs = match jobs.parse() {
+                    Ok(0) | Err(_) => return Err(format_err!("Invalid number of jobs: {}", jobs)),
+                    Ok(jobs) => Some(jobs),
+                };
+            }
+            "--answers" if command == "verify" => {
+                let path = it.next()
+                    .ok_or_else(|| format_err!("Missing value for --answers"))?;
+                options.answers = Some(path.clone());
+            }
+            "--runs" if command == "bench" => {
+                let runs = it.next()
+                    .ok_or_else(|| format_err!("Missing value for --runs"))?;
+                options.runs = match runs.parse() {
+                    Ok(0) | Err(_) => return Err(format_err!("Invalid number of runs: {}", runs)),
+                    Ok(runs) => Some(runs),
+                };
+            }
+            "--save" if command == "bench" => {
+                let path = it.next()
+                    .ok_or_else(|| format_err!("Missing value for --save"))?;
+                options.save = Some(path.clone());
+            }
+            "--baseline" if command == "bench" => {
+                let path = it.next()
+                    .ok_or_else(|| format_err!("Missing value for --baseline"))?;
+                options.baseline = Some(path.clone());
+            }
+            "--steps" if command == "profile" => {
+                let steps = it.next()
+                    .ok_or_else(|| format_err!("Missing value for --steps"))?;
+                options.steps = Some(steps
+                    .parse()
+                    .map_err(|_| format_err!("Invalid number of steps: {}", steps))?);
+            }
+            "--trace" if command == "profile" => {
+                let path = it.next()
+                    .ok_or_else(|| format_err!("Missing value for --trace"))?;
+                options.trace = Some(path.clone());
+            }
+            "--csv" if command == "profile" => {
+                let path = it.next()
+                    .ok_or_else(|| format_err!("Missing value for --csv"))?;
+                options.csv = Some(path.clone());
+            }
+            "--loops-csv" if command == "profile" => {
+                let path = it.next()
+                    .ok_or_else(|| format_err!("Missing value for --loops-csv"))?;
+                options.loops_csv = Some(path.clone());
+            }
+            day => {
+                let day = day.parse()
+                    .map_err(|_| format_err!("Unknown argument: {}\n{}", day, USAGE))?;
+                options.day = Some(day);
+            }
+        }
+    }
+
+    match options {
+        Options {
+            day: Some(_),
+            all: true,
+            ..
+        } => Err(format_err!("Cannot use both a day and --all")),
+        Options {
+            day: None,
+            all: false,
+            ..
+        } => Err(format_err!("Missing day or --all\n{}", USAGE)),
+        Options {
+            all: true,
+            input: Some(_),
+            ..
+        } => Err(format_err!("Cannot use --input with --all")),
+        _ => Ok(options),
+    }
+}
+
+fn run_days(options: &Options) -> Result<(), Error> {
+    if let Some(jobs) = options.jobs {
+        return run_days_parallel(options, jobs);
+    }
+
+    let mut failures = 0;
+
+    if let Some(header) = options.format.header() {
+        println!("{}", header);
+    }
+
+    for day in selected_days(options)? {
+        let input = read_input(&day, options)?;
+
+        for answer in runner::run(&day, &input, &selected_parts(&day, options)?) {
+            if answer.answer.is_err() {
+                failures += 1;
+            }
+
+            if answer.answer.is_err() && options.format == Format::Text {
+                eprintln!("{}", options.format.render(&answer));
+            } else {
+                println!("{}", options.format.render(&answer));
+            }
+        }
+    }
+
+    if failures == 0 {
+        Ok(())
+    } else {
+        Err(format_err!("{} part(s) failed", failures))
+    }
+}
+
+/// Solves every selected part on `jobs` threads, then prints the answers in day order: as a
+/// summary table with the text format, as usual otherwise.
+fn run_days_parallel(options: &Options, jobs: usize) -> Result<(), Error> {
+    let mut tasks = Vec::new();
+
+    for day in selected_days(options)? {
+        let input = read_input(&day, options)?;
+        let parts = selected_parts(&day, options)?;
+        tasks.push(runner::Task { day, input, parts });
+    }
+
+    let start = Instant::now();
+    let answers = runner::run_parallel(tasks, jobs);
+    let elapsed = start.elapsed();
+
+    let failures = answers.iter().filter(|answer| answer.answer.is_err()).count();
+
+    if options.format == Format::Text {
+        println!("{:>3}  {:<4}  {:<32}  {:>10}", "day", "part", "answer", "time_ms");
+
+        for answer in &answers {
+            let value = match answer.answer {
+                Ok(ref value) => value.clone(),
+                Err(ref err) => format!("error: {}", err),
+            };
+
+            println!(
+                "{:>3}  {:<4}  {:<32}  {:>10.3}",
+                answer.day,
+                answer.part,
+                value,
+                output::millis(answer.elapsed)
+            );
+        }
+
+        println!(
+            "{} part(s) in {:.3} ms on {} thread(s)",
+            answers.len(),
+            output::millis(elapsed),
+            jobs
+        );
+    } else {
+        if let Some(header) = options.format.header() {
+            println!("{}", header);
+        }
+
+        for answer in &answers {
+            println!("{}", options.format.render(answer));
+        }
+    }
+
+    if failures == 0 {
+        Ok(())
+    } else {
+        Err(format_err!("{} part(s) failed", failures))
+    }
+}
+
+fn verify_days(options: &Options) -> Result<(), Error> {
+    let answers = match options.answers {
+        Some(ref path) => verify::read(path)?,
+        None => verify::read(verify::default_path())?,
+    };
+
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+
+    for day in selected_days(options)? {
+        let input = read_input(&day, options)?;
+
+        for answer in runner::run(&day, &input, &selected_parts(&day, options)?) {
+            let name = format!("day {:>2} {}", answer.day, answer.part);
+
+            match verify::check(&answer, &answers) {
+                Verdict::Pass => {
+                    passed += 1;
+                    println!("{}: ok", name);
+                }
+                Verdict::Fail { expected, actual } => {
+                    failed += 1;
+                    println!("{}: FAIL", name);
+                    println!("    expected: {}", expected);
+                    println!("    actual:   {}", actual);
+                }
+                Verdict::Error(err) => {
+                    failed += 1;
+                    println!("{}: error: {}", name, err);
+                }
+                Verdict::Unknown(actual) => {
+                    unknown += 1;
+                    println!("{}: no expected answer, got: {}", name, actual);
+                }
+            }
+        }
+    }
+
+    println!(
+        "{} passed, {} failed, {} without expected answer",
+        passed, failed, unknown
+    );
+
+    if failed == 0 {
+        Ok(())
+    } else {
+        Err(format_err!("{} part(s) failed", failed))
+    }
+}
+
+fn bench_days(options: &Options) -> Result<(), Error> {
+    let baseline = match options.baseline {
+        Some(ref path) => Some(bench::read_baseline(path)?),
+        None => None,
+    };
+
+    let mut timings: Vec<Timing> = Vec::new();
+
+    println!(
+        "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}  {:>8}",
+        "day", "step", "min_ms", "median_ms", "base_ms", "change"
+    );
+
+    for day in selected_days(options)? {
+        let input = read_input(&day, options)?;
+        let parts = selected_parts(&day, options)?;
+
+        for timing in bench::measure(&day, &input, &parts, options.runs.unwrap_or(5))? {
+            let (base, change) = match baseline {
+                Some(ref baseline) => (
+                    baseline
+                        .get(&(timing.day, timing.step))
+                        .map(|median| format!("{:.3}", median)),
+                    bench::change(&timing, baseline).map(|change| format!("{:+.1}%", change * 100.0)),
+                ),
+                None => (None, None),
+            };
+
+            println!(
+                "{:>3}  {:<5}  {:>10.3}  {:>10.3}  {:>10}  {:>8}",
+                timing.day,
+                timing.step,
+                output::millis(timing.min()),
+                output::millis(timing.median()),
+                base.unwrap_or_default(),
+                change.unwrap_or_default()
+            );
+
+            timings.push(timing);
+        }
+    }
+
+    if let Some(ref path) = options.save {
+        bench::save_baseline(path, &timings)?;
+    }
+
+    Ok(())
+}
+
+/// Debugs the program of a day, as run by part a unless another part is given.
+fn debug_day(options: &Options) -> Result<(), Error> {
+    if options.all {
+        return Err(format_err!("Cannot debug every day at once"));
+    }
+    if options.input.as_ref().map_or(false, |path| path == "-") {
+        return Err(format_err!("Cannot read the input from the standard input while debugging"));
+    }
+
+    let day = registry::get(options.day.unwrap_or_default())?;
+    let program = read_input(&day, options)?;
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+
+    debugger::debug(
+        day.day,
+        options.part.unwrap_or(Part::A),
+        &program,
+        stdin.lock(),
+        &mut stdout.lock(),
+    )
+}
+
+/// Profiles the program of a day, as run by part a unless another part is given.
+fn profile_day(options: &Options) -> Result<(), Error> {
+    if options.all {
+        return Err(format_err!("Cannot profile every day at once"));
+    }
+
+    let day = registry::get(options.day.unwrap_or_default())?;
+    let program = read_input(&day, options)?;
+    let trace = match options.trace {
+        Some(ref path) => Some(CsvTrace::new(BufWriter::new(create(path)?))),
+        None => None,
+    };
+
+    let profiler = Profiler {
+        steps: options.steps,
+        trace,
+    };
+    let report = debugger::start(day.day, options.part.unwrap_or(Part::A), &program, profiler)?;
+
+    let stdout = io::stdout();
+    report.profile.write_summary(&report.program, 5, stdout.lock())?;
+    if !report.ended {
+        println!("Stopped after {} step(s)", options.steps.unwrap_or_default());
+    }
+
+    if let Some(ref path) = options.csv {
+        report.profile.write_csv(&report.program, create(path)?)?;
+    }
+    if let Some(ref path) = options.loops_csv {
+        report.profile.write_loops_csv(create(path)?)?;
+    }
+
+    Ok(())
+}
+
+fn create(path: &str) -> Result<File, Error> {
+    File::create(path).map_err(|err| format_err!("{}: {}", path, err))
+}
+
+fn selected_days(options: &Options) -> Result<Vec<Day>, Error> {
+    match options.day {
+        Some(day) => Ok(vec![registry::get(day)?]),
+        None => Ok(registry::all()),
+    }
+}
+
+fn read_input(day: &Day, options: &Options) -> Result<String, Error> {
+    match options.input {
+        Some(ref path) => input::read(path),
+        None => input::read(day.input_path()),
+    }
+}
+
+fn selected_parts(day: &Day, options: &Options) -> Result<Vec<Part>, Error> {
+    match options.part {
+        Some(part) if !day.parts.contains(&part) => if options.all {
+            // Days without the requested part (day 25 has no part b) are skipped
+            Ok(vec![])
+        } else {
+            Err(format_err!("Day {} has no part {}", day.day, part))
+        },
+        Some(part) => Ok(vec![part]),
+        None => Ok(day.parts.to_vec()),
+    }
+}
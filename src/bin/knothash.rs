@@ -0,0 +1,392 @@
+extern crate adventofcode2017;
+#[macro_use]
+extern crate failure;
+
+use adventofcode2017::knot::{KnotHashConfig, SUFFIX};
+use adventofcode2017::knot_search::{Progress, Search};
+use failure::Error;
+use std::env;
+use std::fs::File;
+use std::io::{self, Read};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const USAGE: &str = "Usage:
+    knothash [--rounds <n>] [--size <n>] [--suffix <n,...>] [<path|->...] [--string <text>...]
+    knothash [--rounds <n>] [--size <n>] [--suffix <n,...>] --check <manifest>
+    knothash [--rounds <n>] [--size <n>] [--suffix <n,...>] --search <prefix> [<search options>]
+    knothash [--rounds <n>] [--size <n>] [--suffix <n,...>] --collide <digits> [<search options>]
+
+Prints the knot hash of each file, of the standard input (the default, or -) and of each
+--string, followed by its name, as sha256sum does. --check reads such lines from a manifest
+and checks the hash of each file against them.
+
+--rounds, --size and --suffix change the number of rounds, 64, the size of the list, 256, and
+the lengths appended to the input, 17,31,73,47,23 (an empty --suffix appends none). No byte of
+the input, nor length of the suffix, can be larger than the list.
+
+--search looks for a string whose hash starts with a prefix, and --collide for two strings
+whose hashes have the same first digits, trying every string of the alphabet up to the
+maximum length, shortest first. Search options:
+    --alphabet <chars>   defaults to the lowercase letters and digits
+    --max-length <n>     defaults to 6
+    --jobs <n>           threads hashing the strings, defaults to 4";
+
+const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyz0123456789";
+
+fn main() {
+    if let Err(ref err) = run() {
+        eprintln!("error: {}", err);
+        ::std::process::exit(1);
+    }
+}
+
+#[derive(Debug)]
+enum Source {
+    Stdin,
+    File(String),
+    Text(String),
+}
+
+impl Source {
+    fn name(&self) -> String {
+        match *self {
+            Source::Stdin => String::from("-"),
+            Source::File(ref path) => path.clone(),
+            Source::Text(ref text) => format!("{:?}", text),
+        }
+    }
+
+    fn read(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = Vec::new();
+
+        match *self {
+            Source::Stdin => {
+                io::stdin().read_to_end(&mut bytes)?;
+            }
+            Source::File(ref path) => {
+                File::open(path)
+                    .and_then(|mut file| file.read_to_end(&mut bytes))
+                    .map_err(|err| format_err!("{}: {}", path, err))?;
+            }
+            Source::Text(ref text) => bytes.extend_from_slice(text.as_bytes()),
+        }
+
+        Ok(bytes)
+    }
+}
+
+fn source(path: &str) -> Source {
+    if path == "-" {
+        Source::Stdin
+    } else {
+        Source::File(path.to_owned())
+    }
+}
+
+#[derive(Debug)]
+enum Goal {
+    Preimage(String),
+    Collision(usize),
+}
+
+#[derive(Debug, Default)]
+struct Options {
+    rounds: Option<usize>,
+    size: Option<usize>,
+    suffix: Option<Vec<u8>>,
+    sources: Vec<Source>,
+    check: Option<String>,
+    goal: Option<Goal>,
+    alphabet: Option<String>,
+    max_length: Option<usize>,
+    jobs: Option<usize>,
+}
+
+fn run() -> Result<(), Error> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = parse_options(&args)?;
+
+    let mut config = KnotHashConfig::new();
+    if let Some(rounds) = options.rounds {
+        config = config.rounds(rounds);
+    }
+    let size = options.size.unwrap_or(256);
+    config = config.list_size(size);
+    if let Some(ref suffix) = options.suffix {
+        config = config.suffix(suffix);
+    }
+
+    if let Some(ref goal) = options.goal {
+        return search(config, size, goal, &options);
+    }
+
+    match options.check {
+        Some(ref manifest) => check(&config, size, manifest),
+        None if options.sources.is_empty() => print_hash(&config, size, &Source::Stdin),
+        None => options
+            .sources
+            .iter()
+            .map(|source| print_hash(&config, size, source))
+            .collect(),
+    }
+}
+
+/// Checks that each byte of `bytes`, tied as a length, fits in the list of `size` elements, as
+/// the suffix was checked to while parsing the options.
+fn check_lengths(bytes: &[u8], size: usize) -> Result<(), Error> {
+    match bytes.iter().find(|&&b| usize::from(b) > size) {
+        Some(b) => Err(format_err!("length {} is larger than the list of {}", b, size)),
+        None => Ok(()),
+    }
+}
+
+fn parse_options(args: &[String]) -> Result<Options, Error> {
+    let mut options = Options::default();
+    let mut it = args.iter();
+
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--rounds" => {
+                let rounds = it.next()
+                    .ok_or_else(|| format_err!("Missing value for --rounds"))?;
+                options.rounds = Some(rounds
+                    .parse()
+                    .map_err(|_| format_err!("Invalid number of rounds: {}", rounds))?);
+            }
+            "--size" => {
+                let size = it.next()
+                    .ok_or_else(|| format_err!("Missing value for --size"))?;
+                options.size = match size.parse() {
+                    Ok(0) | Err(_) => return Err(format_err!("Invalid list size: {}", size)),
+                    Ok(size) => Some(size),
+                };
+            }
+            "--suffix" => {
+                let suffix = it.next()
+                    .ok_or_else(|| format_err!("Missing value for --suffix"))?;
+                options.suffix = Some(suffix
+                    .split(',')
+                    .filter(|length| !length.is_empty())
+                    .map(|length| {
+                        length
+                            .trim()
+                            .parse()
+                            .map_err(|_| format_err!("Invalid suffix length: {}", length))
+                    })
+                    .collect::<Result<_, _>>()?);
+            }
+            "--string" => {
+                let text = it.next()
+                    .ok_or_else(|| format_err!("Missing value for --string"))?;
+                options.sources.push(Source::Text(text.clone()));
+            }
+            "--check" => {
+                let path = it.next()
+                    .ok_or_else(|| format_err!("Missing value for --check"))?;
+                options.check = Some(path.clone());
+            }
+            "--search" => {
+                let prefix = it.next()
+                    .ok_or_else(|| format_err!("Missing value for --search"))?;
+                options.goal = Some(Goal::Preimage(prefix.clone()));
+            }
+            "--collide" => {
+                let digits = it.next()
+                    .ok_or_else(|| format_err!("Missing value for --collide"))?;
+                options.goal = match digits.parse() {
+                    Ok(0) | Err(_) => {
+                        return Err(format_err!("Invalid number of digits: {}", digits))
+                    }
+                    Ok(digits) => Some(Goal::Collision(digits)),
+                };
+            }
+            "--alphabet" => {
+                let alphabet = it.next()
+                    .ok_or_else(|| format_err!("Missing value for --alphabet"))?;
+                // A repeated byte would give the same strings several times
+                let bytes = alphabet.as_bytes();
+                if let Some(i) = (1..bytes.len()).find(|&i| bytes[..i].contains(&bytes[i])) {
+                    let repeated = char::from(bytes[i]);
+                    return Err(format_err!("Repeated byte in --alphabet: {:?}", repeated));
+                }
+                options.alphabet = Some(alphabet.clone());
+            }
+            "--max-length" => {
+                let length = it.next()
+                    .ok_or_else(|| format_err!("Missing value for --max-length"))?;
+                options.max_length = Some(length
+                    .parse()
+                    .map_err(|_| format_err!("Invalid maximum length: {}", length))?);
+            }
+            "--jobs" => {
+                let jobs = it.next()
+                    .ok_or_else(|| format_err!("Missing value for --jobs"))?;
+                options.jobs = match jobs.parse() {
+                    Ok(0) | Err(_) => return Err(format_err!("Invalid number of jobs: {}", jobs)),
+                    Ok(jobs) => Some(jobs),
+                };
+            }
+            "help" | "--help" | "-h" => {
+                println!("{}", USAGE);
+                ::std::process::exit(0);
+            }
+            path if path.starts_with("--") => {
+                return Err(format_err!("Unknown argument: {}\n{}", path, USAGE))
+            }
+            path => options.sources.push(source(path)),
+        }
+    }
+
+    let size = options.size.unwrap_or(256);
+    let suffix = options.suffix.as_ref().map_or(&SUFFIX[..], Vec::as_slice);
+    if let Some(&length) = suffix.iter().find(|&&length| usize::from(length) > size) {
+        return Err(format_err!(
+            "The list size {} is smaller than the length {} of the suffix {:?}, see --suffix",
+            size,
+            length,
+            suffix
+        ));
+    }
+
+    if options.check.is_some() && !options.sources.is_empty() {
+        return Err(format_err!("Cannot hash files or strings with --check\n{}", USAGE));
+    }
+    if options.goal.is_some() && (options.check.is_some() || !options.sources.is_empty()) {
+        return Err(format_err!("Cannot hash or check files while searching\n{}", USAGE));
+    }
+
+    Ok(options)
+}
+
+fn print_hash(config: &KnotHashConfig, size: usize, source: &Source) -> Result<(), Error> {
+    let bytes = source.read()?;
+    check_lengths(&bytes, size).map_err(|err| format_err!("{}: {}", source.name(), err))?;
+
+    println!("{}  {}", config.encode(&bytes), source.name());
+    Ok(())
+}
+
+/// Checks every `<hash>  <path>` line of `manifest`, printing whether each file matches.
+fn check(config: &KnotHashConfig, size: usize, manifest: &str) -> Result<(), Error> {
+    let text = String::from_utf8(source(manifest).read()?)
+        .map_err(|_| format_err!("{}: not valid UTF-8", manifest))?;
+
+    let (mut checked, mut failed) = (0, 0);
+
+    for (i, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (expected, path) = match line.find(' ') {
+            Some(space) => (&line[..space], &line[space + 1..]),
+            None => return Err(format_err!("{}:{}: expected <hash>  <path>", manifest, i + 1)),
+        };
+        // sha256sum marks files read in text or binary mode, which are the same here
+        let path = path.trim_left_matches(|c| c == ' ' || c == '*');
+
+        checked += 1;
+        let read = source(path).read().and_then(|bytes| {
+            check_lengths(&bytes, size).map_err(|err| format_err!("{}: {}", path, err))?;
+            Ok(bytes)
+        });
+        match read {
+            Ok(ref bytes) if config.encode(bytes) == expected.to_ascii_lowercase() => {
+                println!("{}: OK", path)
+            }
+            Ok(_) => {
+                failed += 1;
+                println!("{}: FAILED", path);
+            }
+            Err(err) => {
+                failed += 1;
+                eprintln!("error: {}", err);
+                println!("{}: FAILED open or read", path);
+            }
+        }
+    }
+
+    if failed == 0 {
+        Ok(())
+    } else {
+        Err(format_err!(
+            "{} of {} computed checksum(s) did NOT match",
+            failed,
+            checked
+        ))
+    }
+}
+
+/// Runs the search on its own threads, printing its progress on the standard error every second.
+fn search(
+    config: KnotHashConfig,
+    size: usize,
+    goal: &Goal,
+    options: &Options,
+) -> Result<(), Error> {
+    let alphabet = options.alphabet.as_ref().map_or(ALPHABET, String::as_str);
+    check_lengths(alphabet.as_bytes(), size).map_err(|err| format_err!("--alphabet: {}", err))?;
+    let search = Search {
+        config,
+        alphabet: alphabet.bytes().collect(),
+        max_length: options.max_length.unwrap_or(6),
+        jobs: options.jobs.unwrap_or(4),
+    };
+    let total = search.candidates();
+
+    let progress = Progress::new();
+    let start = Instant::now();
+    let reporter = {
+        let progress = progress.clone();
+        thread::spawn(move || {
+            let mut last = Instant::now();
+
+            while !progress.is_done() {
+                thread::sleep(Duration::from_millis(100));
+
+                if last.elapsed() >= Duration::from_secs(1) {
+                    report(&progress, total, start);
+                    last = Instant::now();
+                }
+            }
+        })
+    };
+
+    let found = match *goal {
+        Goal::Preimage(ref prefix) => search
+            .preimage(prefix, &progress)
+            .map(|preimage| vec![preimage]),
+        Goal::Collision(digits) => search
+            .collision(digits, &progress)
+            .map(|(a, b)| vec![a, b]),
+    };
+
+    report(&progress, total, start);
+    reporter.join().ok();
+
+    match found {
+        Some(found) => {
+            for candidate in found {
+                let text = String::from_utf8_lossy(&candidate);
+                println!("{}  {:?}", search.config.encode(&candidate), text);
+            }
+            Ok(())
+        }
+        None => Err(format_err!("Nothing found in {} strings", progress.tried())),
+    }
+}
+
+fn report(progress: &Arc<Progress>, total: usize, start: Instant) {
+    let elapsed = start.elapsed();
+    let seconds = elapsed.as_secs() as f64 + f64::from(elapsed.subsec_nanos()) / 1e9;
+
+    eprintln!(
+        "{} of {} strings hashed in {:.1} s, {:.0} per second",
+        progress.tried(),
+        total,
+        seconds,
+        progress.tried() as f64 / seconds
+    );
+}
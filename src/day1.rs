@@ -0,0 +1,84 @@
+use error::Error;
+use parser;
+use solution::Solution;
+
+#[derive(Debug, Default)]
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<u32>;
+    type A = u32;
+    type B = u32;
+
+    fn parse(&self, input: &str) -> Result<Vec<u32>, Error> {
+        parse(input)
+    }
+
+    fn part_a(&self, digits: &Vec<u32>) -> Result<u32, Error> {
+        Ok(run_a(digits))
+    }
+
+    fn part_b(&self, digits: &Vec<u32>) -> Result<u32, Error> {
+        Ok(run_b(digits))
+    }
+}
+
+pub fn run_a(digits: &[u32]) -> u32 {
+    captcha_sum(digits, 1)
+}
+
+pub fn run_b(digits: &[u32]) -> u32 {
+    captcha_sum(digits, digits.len() / 2)
+}
+
+/// Digits of the captcha.
+pub fn parse(input: &str) -> Result<Vec<u32>, Error> {
+    let line = parser::single_line(1, input)?;
+
+    line.text
+        .chars()
+        .enumerate()
+        .map(|(j, c)| {
+            c.to_digit(10)
+                .ok_or_else(|| line.error_at(j + 1, format!("expected a digit, found {:?}", c)))
+        })
+        .collect()
+}
+
+fn captcha_sum(data: &[u32], shift: usize) -> u32 {
+    data.iter()
+        .zip(data.iter().cycle().skip(shift))
+        .filter(|&(a, b)| a == b)
+        .map(|(a, _)| a)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_a() {
+        assert_eq!(3, run_a(&parse("1122").unwrap()));
+        assert_eq!(4, run_a(&parse("1111").unwrap()));
+        assert_eq!(0, run_a(&parse("1234").unwrap()));
+        assert_eq!(9, run_a(&parse("91212129").unwrap()));
+    }
+
+    #[test]
+    fn test_run_b() {
+        assert_eq!(6, run_b(&parse("1212").unwrap()));
+        assert_eq!(0, run_b(&parse("1221").unwrap()));
+        assert_eq!(4, run_b(&parse("123425").unwrap()));
+        assert_eq!(12, run_b(&parse("123123").unwrap()));
+        assert_eq!(4, run_b(&parse("12131415").unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Err(Error::parse(1, 1, 3, "expected a digit, found 'x'").with_source("12x4")),
+            parse("12x4")
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use error::Error;
+use {knot_hash, knot_hash_partial};
+use parser;
+use solution::Solution;
+
+#[derive(Debug)]
+pub struct Day10 {
+    pub list_size: usize,
+}
+
+impl Default for Day10 {
+    fn default() -> Day10 {
+        Day10 { list_size: 256 }
+    }
+}
+
+impl Solution for Day10 {
+    type Input = Lengths;
+    type A = usize;
+    type B = String;
+
+    fn parse(&self, input: &str) -> Result<Lengths, Error> {
+        parse(input, self.list_size)
+    }
+
+    fn part_a(&self, input: &Lengths) -> Result<usize, Error> {
+        Ok(run_a(&input.lengths, self.list_size))
+    }
+
+    fn part_b(&self, input: &Lengths) -> Result<String, Error> {
+        Ok(run_b(&input.text))
+    }
+}
+
+/// The line of lengths, read as numbers by part a and as bytes by part b.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lengths {
+    pub text: String,
+    pub lengths: Vec<usize>,
+}
+
+pub fn parse(input: &str, list_size: usize) -> Result<Lengths, Error> {
+    let line = parser::single_line(10, input)?;
+    let lengths = line.split(",")
+        .filter(|n| !n.is_empty())
+        .map(|n| match line.parse(n, "a length")? {
+            length if length > list_size => Err(line.error(
+                n,
+                format!("length {} is larger than the list of {}", length, list_size),
+            )),
+            length => Ok(length),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Lengths {
+        text: line.text.to_owned(),
+        lengths,
+    })
+}
+
+pub fn run_a(lengths: &[usize], list_size: usize) -> usize {
+    knot_hash_partial(lengths, list_size, 1)
+        .iter()
+        .take(2)
+        .product()
+}
+
+pub fn run_b(input: &str) -> String {
+    knot_hash(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_a() {
+        assert_eq!(0, run_a(&parse("", 5).unwrap().lengths, 5));
+        assert_eq!(2, run_a(&parse("3", 5).unwrap().lengths, 5));
+        assert_eq!(12, run_a(&parse("3, 4", 5).unwrap().lengths, 5));
+        assert_eq!(12, run_a(&parse("3, 4, 1", 5).unwrap().lengths, 5));
+        assert_eq!(12, run_a(&parse("3, 4, 1, 5", 5).unwrap().lengths, 5));
+    }
+
+    #[test]
+    fn test_run_b() {
+        assert_eq!("a2582a3a0e66e6e86e3812dcb672a272", run_b(""));
+        assert_eq!("33efeb34ea91902bb2f59c9920caa6cd", run_b("AoC 2017"));
+        assert_eq!("3efbe78a8d82f29979031a4aa0b16a9d", run_b("1,2,3"));
+        assert_eq!("63960835bcdc130f0b66d7ff4f6a5a8e", run_b("1,2,4"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err(Error::parse(10, 1, 4, "length 6 is larger than the list of 5")
+                .with_source("3, 6")),
+            parse("3, 6", 5)
+        );
+        assert!(parse("3, x", 5).is_err());
+    }
+}
@@ -0,0 +1,124 @@
+use error::Error;
+use parser;
+use solution::Solution;
+use std::collections::HashMap;
+
+#[derive(Debug, Default)]
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<&'static str>;
+    type A = usize;
+    type B = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<&'static str>, Error> {
+        parse(input)
+    }
+
+    fn part_a(&self, path: &Vec<&'static str>) -> Result<usize, Error> {
+        run_a(path)
+    }
+
+    fn part_b(&self, path: &Vec<&'static str>) -> Result<usize, Error> {
+        run_b(path)
+    }
+}
+
+const DIRECTIONS: [&str; 6] = ["n", "ne", "se", "s", "sw", "nw"];
+
+/// Directions of the path, each one of `DIRECTIONS`.
+pub fn parse(input: &str) -> Result<Vec<&'static str>, Error> {
+    let line = parser::single_line(11, input)?;
+
+    line.split(",")
+        .map(|direction| match DIRECTIONS.iter().find(|&&d| d == direction) {
+            Some(direction) => Ok(*direction),
+            None => Err(line.error(
+                direction,
+                format!("expected a direction, found {:?}", direction),
+            )),
+        })
+        .collect()
+}
+
+pub fn run_a(path: &[&'static str]) -> Result<usize, Error> {
+    walk_hex_grid(path)
+        .last()
+        .cloned()
+        .ok_or_else(|| Error::runtime(11, "Empty path"))
+}
+
+pub fn run_b(path: &[&'static str]) -> Result<usize, Error> {
+    walk_hex_grid(path)
+        .into_iter()
+        .max()
+        .ok_or_else(|| Error::runtime(11, "Empty path"))
+}
+
+fn walk_hex_grid(path: &[&'static str]) -> Vec<usize> {
+    let mut aggregated_path = HashMap::new();
+
+    path.iter()
+        .map(|&direction| {
+            *aggregated_path.entry(direction).or_insert(0) += 1;
+
+            simplify_edges(&["n", "s"], &mut aggregated_path);
+            simplify_edges(&["ne", "sw"], &mut aggregated_path);
+            simplify_edges(&["nw", "se"], &mut aggregated_path);
+            simplify_edges(&["n", "sw", "se"], &mut aggregated_path);
+            simplify_edges(&["s", "nw", "ne"], &mut aggregated_path);
+
+            distance(&aggregated_path)
+        })
+        .collect()
+}
+
+fn simplify_edges<'a>(edges: &[&'a str], aggregated_path: &mut HashMap<&'a str, usize>) {
+    if let Some(min) = edges
+        .iter()
+        .map(|key| *aggregated_path.entry(key).or_insert(0))
+        .min()
+    {
+        edges
+            .iter()
+            .for_each(|key| *aggregated_path.entry(key).or_insert(0) -= min);
+    }
+}
+
+fn distance(simplified_path: &HashMap<&str, usize>) -> usize {
+    let all_directions: Vec<_> = DIRECTIONS
+        .iter()
+        .map(|key| simplified_path[key])
+        .collect();
+
+    match *all_directions.as_slice() {
+        [a, b, c, 0, 0, 0]
+        | [0, a, b, c, 0, 0]
+        | [0, 0, a, b, c, 0]
+        | [0, 0, 0, a, b, c]
+        | [c, 0, 0, 0, a, b]
+        | [b, c, 0, 0, 0, a] => a.max(c) + b,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_a() {
+        assert_eq!(Ok(3), run_a(&parse("ne,ne,ne").unwrap()));
+        assert_eq!(Ok(0), run_a(&parse("ne,ne,sw,sw").unwrap()));
+        assert_eq!(Ok(2), run_a(&parse("ne,ne,s,s").unwrap()));
+        assert_eq!(Ok(3), run_a(&parse("se,sw,se,sw,sw").unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Err(Error::parse(11, 1, 4, "expected a direction, found \"e\"").with_source("ne,e")),
+            parse("ne,e")
+        );
+    }
+}
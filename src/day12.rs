@@ -0,0 +1,138 @@
+use error::Error;
+use parser;
+use solution::Solution;
+
+#[derive(Debug, Default)]
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Vec<usize>>;
+    type A = usize;
+    type B = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<Vec<usize>>, Error> {
+        make_village(input)
+    }
+
+    fn part_a(&self, input: &Vec<Vec<usize>>) -> Result<usize, Error> {
+        Ok(run_a(input))
+    }
+
+    fn part_b(&self, input: &Vec<Vec<usize>>) -> Result<usize, Error> {
+        Ok(run_b(input))
+    }
+}
+
+pub fn run_a(input: &[Vec<usize>]) -> usize {
+    let mut visited = vec![false; input.len()];
+    let mut stack = vec![0];
+
+    while let Some(program) = stack.pop() {
+        visited[program] = true;
+        input[program].iter().for_each(|&n| {
+            if !visited[n] {
+                stack.push(n)
+            }
+        });
+    }
+
+    visited.iter().filter(|&n| *n).count()
+}
+
+pub fn run_b(input: &[Vec<usize>]) -> usize {
+    let mut visited = vec![false; input.len()];
+    let mut stack = Vec::new();
+    let mut count = 0;
+
+    while let Some(orphean) = visited.iter().enumerate().position(|(_, &n)| !n) {
+        stack.push(orphean);
+        count += 1;
+
+        while let Some(program) = stack.pop() {
+            visited[program] = true;
+            input[program].iter().for_each(|&n| {
+                if !visited[n] {
+                    stack.push(n)
+                }
+            });
+        }
+    }
+
+    count
+}
+
+/// Programs each of the programs, listed in order, can talk to.
+pub fn make_village(input: &str) -> Result<Vec<Vec<usize>>, Error> {
+    let lines: Vec<_> = parser::lines(12, input).collect();
+
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let mut sides = line.split("<->");
+
+            let program = sides.token("a program")?;
+            if line.parse::<usize>(program, "a program")? != i {
+                return Err(line.error(program, format!("expected program {}", i)));
+            }
+
+            let pipes = sides.token("`<->`")?;
+            sides.end()?;
+
+            pipes
+                .split(',')
+                .map(|n| match line.parse(n.trim(), "a program")? {
+                    n if n < lines.len() => Ok(n),
+                    _ => Err(line.error(n.trim(), format!("unknown program {}", n.trim()))),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_a() {
+        let input = "0 <-> 2
+1 <-> 1
+2 <-> 0, 3, 4
+3 <-> 2, 4
+4 <-> 2, 3, 6
+5 <-> 6
+6 <-> 4, 5
+";
+        let input = make_village(&input).unwrap();
+        assert_eq!(6, run_a(&input));
+    }
+
+    #[test]
+    fn test_run_b() {
+        let input = "0 <-> 2
+1 <-> 1
+2 <-> 0, 3, 4
+3 <-> 2, 4
+4 <-> 2, 3, 6
+5 <-> 6
+6 <-> 4, 5
+";
+
+        let input = make_village(&input).unwrap();
+        assert_eq!(2, run_b(&input));
+    }
+
+    #[test]
+    fn test_make_village_errors() {
+        assert_eq!(
+            Err(Error::parse(12, 2, 1, "expected program 1").with_source("2 <-> 0")),
+            make_village("0 <-> 1\n2 <-> 0")
+        );
+        assert_eq!(
+            Err(Error::parse(12, 1, 7, "unknown program 2").with_source("0 <-> 2")),
+            make_village("0 <-> 2")
+        );
+        assert!(make_village("0 2").is_err());
+    }
+}
@@ -0,0 +1,106 @@
+use error::Error;
+use parser;
+use solution::Solution;
+
+#[derive(Debug, Default)]
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<(u32, u32)>;
+    type A = u32;
+    type B = u32;
+
+    fn parse(&self, input: &str) -> Result<Vec<(u32, u32)>, Error> {
+        parse(input)
+    }
+
+    fn part_a(&self, layers: &Vec<(u32, u32)>) -> Result<u32, Error> {
+        Ok(run_a(layers))
+    }
+
+    fn part_b(&self, layers: &Vec<(u32, u32)>) -> Result<u32, Error> {
+        Ok(run_b(layers))
+    }
+}
+
+pub fn run_a(layers: &[(u32, u32)]) -> u32 {
+    severity(layers, 0)
+}
+
+pub fn run_b(layers: &[(u32, u32)]) -> u32 {
+    let mut delay = 0;
+
+    while severity(layers, delay) != 0 {
+        delay += 1;
+    }
+
+    delay
+}
+
+fn severity(input: &[(u32, u32)], delay: u32) -> u32 {
+    input
+        .iter()
+        .map(|&(time, range)| (time + delay, range))
+        .filter(|&(time, range)| {
+            if range == 1 {
+                return true;
+            }
+
+            let range = 2 * range - 2;
+            time % range == 0
+        })
+        .fold(0, |acc, (time, range)| acc + time * range)
+}
+
+/// Depth and range of each layer with a scanner.
+pub fn parse(input: &str) -> Result<Vec<(u32, u32)>, Error> {
+    parser::lines(13, input)
+        .filter(|line| !line.text.trim().is_empty())
+        .map(|line| {
+            let mut it = line.split(":");
+
+            let time = it.parse("a depth")?;
+            let range = it.token("a range")?;
+            it.end()?;
+
+            match line.parse(range, "a range")? {
+                0 => Err(line.error(range, "expected a range of at least 1")),
+                range => Ok((time, range)),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_a() {
+        let input = "0: 3
+1: 2
+4: 4
+6: 4
+";
+        assert_eq!(24, run_a(&parse(input).unwrap()));
+    }
+
+    #[test]
+    fn test_run_b() {
+        let input = "0: 3
+    1: 2
+    4: 4
+    6: 4
+    ";
+        assert_eq!(10, run_b(&parse(input).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err(Error::parse(13, 2, 4, "expected a range of at least 1").with_source("1: 0")),
+            parse("0: 3\n1: 0")
+        );
+        assert!(parse("0 3").is_err());
+    }
+}
@@ -0,0 +1,146 @@
+use error::Error;
+use knot_hash_many;
+use parser;
+use solution::Solution;
+
+#[derive(Debug)]
+pub struct Day14 {
+    pub disk_size: usize,
+    /// Threads hashing the rows of the disk.
+    pub jobs: usize,
+}
+
+impl Default for Day14 {
+    fn default() -> Day14 {
+        Day14 {
+            disk_size: 128,
+            jobs: 4,
+        }
+    }
+}
+
+impl Solution for Day14 {
+    type Input = Vec<Vec<bool>>;
+    type A = usize;
+    type B = u32;
+
+    /// Hashes the rows of the disk, which both parts use.
+    fn parse(&self, input: &str) -> Result<Vec<Vec<bool>>, Error> {
+        let mut words = parser::single_line(14, input)?.words();
+        let key = words.token("a key")?;
+        words.end()?;
+
+        Ok(make_disk(key, self.disk_size, self.jobs))
+    }
+
+    fn part_a(&self, disk: &Vec<Vec<bool>>) -> Result<usize, Error> {
+        Ok(run_a(disk))
+    }
+
+    fn part_b(&self, disk: &Vec<Vec<bool>>) -> Result<u32, Error> {
+        Ok(run_b(disk))
+    }
+}
+
+pub fn run_a(disk: &[Vec<bool>]) -> usize {
+    disk.iter()
+        .map(|row| row.iter().filter(|&&used| used).count())
+        .sum()
+}
+
+pub fn run_b(disk: &[Vec<bool>]) -> u32 {
+    let mut disk = disk.to_vec();
+    let disk_size = disk.len();
+    let mut stack = Vec::new();
+    let mut region_count = 0;
+
+    for i in 0..disk_size {
+        for j in 0..disk_size {
+            stack.push((i, j));
+            let mut did_something = false;
+
+            while let Some(region) = stack.pop() {
+                if disk[region.0][region.1] {
+                    disk[region.0][region.1] = false;
+                    did_something = true;
+
+                    if let Some(neighbour) = up(region, 0) {
+                        stack.push(neighbour);
+                    }
+                    if let Some(neighbour) = down(region, disk_size) {
+                        stack.push(neighbour);
+                    }
+                    if let Some(neighbour) = left(region, 0) {
+                        stack.push(neighbour);
+                    }
+                    if let Some(neighbour) = rigth(region, disk_size) {
+                        stack.push(neighbour);
+                    }
+                }
+            }
+
+            if did_something {
+                region_count += 1;
+            }
+        }
+    }
+
+    region_count
+}
+
+fn up(coordinates: (usize, usize), min: usize) -> Option<(usize, usize)> {
+    if coordinates.0 >= (min + 1) {
+        Some((coordinates.0 - 1, coordinates.1))
+    } else {
+        None
+    }
+}
+
+fn down(coordinates: (usize, usize), max: usize) -> Option<(usize, usize)> {
+    if coordinates.0 < (max - 1) {
+        Some((coordinates.0 + 1, coordinates.1))
+    } else {
+        None
+    }
+}
+
+fn left(coordinates: (usize, usize), min: usize) -> Option<(usize, usize)> {
+    if coordinates.1 >= (min + 1) {
+        Some((coordinates.0, coordinates.1 - 1))
+    } else {
+        None
+    }
+}
+
+fn rigth(coordinates: (usize, usize), max: usize) -> Option<(usize, usize)> {
+    if coordinates.1 < (max - 1) {
+        Some((coordinates.0, coordinates.1 + 1))
+    } else {
+        None
+    }
+}
+
+/// Used squares of the disk, one row of `disk_size` squares per hash of `key`.
+pub fn make_disk(key: &str, disk_size: usize, jobs: usize) -> Vec<Vec<bool>> {
+    let rows: Vec<_> = (0..disk_size).map(|i| format!("{}-{}", key, i)).collect();
+
+    knot_hash_many(&rows, jobs)
+        .into_iter()
+        .map(|digest| digest.bits().collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_a() {
+        assert_eq!(8108, run_a(&make_disk("flqrgnkx", 128, 1)));
+    }
+
+    #[test]
+    fn test_run_b() {
+        assert_eq!(1242, run_b(&make_disk("flqrgnkx", 128, 2)));
+    }
+}
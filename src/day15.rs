@@ -0,0 +1,206 @@
+use error::Error;
+use parser;
+use solution::Solution;
+
+#[derive(Debug)]
+pub struct Day15 {
+    pub factor_a: u64,
+    pub factor_b: u64,
+    pub multiple_a: u64,
+    pub multiple_b: u64,
+    pub product: u64,
+    pub pairs_count_a: u64,
+    pub pairs_count_b: u64,
+}
+
+impl Default for Day15 {
+    fn default() -> Day15 {
+        Day15 {
+            factor_a: 16_807,
+            factor_b: 48_271,
+            multiple_a: 4,
+            multiple_b: 8,
+            product: 2_147_483_647,
+            pairs_count_a: 40_000_000,
+            pairs_count_b: 5_000_000,
+        }
+    }
+}
+
+impl Solution for Day15 {
+    type Input = (u64, u64);
+    type A = usize;
+    type B = usize;
+
+    fn parse(&self, input: &str) -> Result<(u64, u64), Error> {
+        parse(input)
+    }
+
+    fn part_a(&self, seeds: &(u64, u64)) -> Result<usize, Error> {
+        Ok(run_a(
+            *seeds,
+            self.factor_a,
+            self.factor_b,
+            self.product,
+            self.pairs_count_a,
+        ))
+    }
+
+    fn part_b(&self, seeds: &(u64, u64)) -> Result<usize, Error> {
+        Ok(run_b(
+            *seeds,
+            self.factor_a,
+            self.factor_b,
+            self.multiple_a,
+            self.multiple_b,
+            self.product,
+            self.pairs_count_b,
+        ))
+    }
+}
+
+pub fn run_a(
+    seeds: (u64, u64),
+    factor_a: u64,
+    factor_b: u64,
+    product: u64,
+    pairs_count: u64,
+) -> usize {
+    judge_final_count(seeds, factor_a, factor_b, 1, 1, product, pairs_count)
+}
+
+pub fn run_b(
+    seeds: (u64, u64),
+    factor_a: u64,
+    factor_b: u64,
+    multiple_a: u64,
+    multiple_b: u64,
+    product: u64,
+    pairs_count: u64,
+) -> usize {
+    judge_final_count(
+        seeds,
+        factor_a,
+        factor_b,
+        multiple_a,
+        multiple_b,
+        product,
+        pairs_count,
+    )
+}
+
+fn generator_next(current: u64, factor: u64, product: u64, multiple: u64) -> u64 {
+    let mut candidate = (factor * current) % product;
+
+    while candidate % multiple != 0 {
+        candidate = (factor * candidate) % product;
+    }
+
+    candidate
+}
+
+fn judge_final_count(
+    (mut a, mut b): (u64, u64),
+    factor_a: u64,
+    factor_b: u64,
+    multiple_a: u64,
+    multiple_b: u64,
+    product: u64,
+    pairs_count: u64,
+) -> usize {
+    (0..pairs_count)
+        .map(|_| {
+            a = generator_next(a, factor_a, product, multiple_a);
+            b = generator_next(b, factor_b, product, multiple_b);
+            (a, b)
+        })
+        .filter(|&(a, b)| {
+            let tail = 0b1111_1111_1111_1111;
+            a & tail == b & tail
+        })
+        .count()
+}
+
+/// Starting values of the generators A and B.
+pub fn parse(input: &str) -> Result<(u64, u64), Error> {
+    let mut lines = parser::lines(15, input);
+    let mut seed = |number: usize, generator: &str| {
+        let line = lines.next().ok_or_else(|| {
+            Error::parse(
+                15,
+                number,
+                1,
+                format!("expected the starting value of generator {}", generator),
+            )
+        })?;
+
+        let mut words = line.words();
+        words.literal("Generator")?;
+        words.literal(generator)?;
+        words.literal("starts")?;
+        words.literal("with")?;
+        let value = words.parse("a starting value")?;
+        words.end()?;
+
+        Ok(value)
+    };
+
+    Ok((seed(1, "A")?, seed(2, "B")?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_a() {
+        let factor_a = 16_807;
+        let factor_b = 48_271;
+        let product = 2_147_483_647;
+        let pairs_count = 40_000_000;
+        let seeds = (65, 8921);
+
+        assert_eq!(588, run_a(seeds, factor_a, factor_b, product, pairs_count));
+    }
+
+    #[test]
+    fn test_run_b() {
+        let factor_a = 16_807;
+        let factor_b = 48_271;
+        let multiple_a = 4;
+        let multiple_b = 8;
+        let product = 2_147_483_647;
+        let pairs_count = 5_000_000;
+        let seeds = (65, 8921);
+
+        assert_eq!(
+            309,
+            run_b(
+                seeds,
+                factor_a,
+                factor_b,
+                multiple_a,
+                multiple_b,
+                product,
+                pairs_count
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Ok((65, 8921)),
+            parse("Generator A starts with 65\nGenerator B starts with 8921")
+        );
+        assert_eq!(
+            Err(Error::parse(15, 2, 1, "expected the starting value of generator B")),
+            parse("Generator A starts with 65")
+        );
+        assert_eq!(
+            Err(Error::parse(15, 2, 11, "expected `B`, found \"A\"")
+                .with_source("Generator A starts with 8921")),
+            parse("Generator A starts with 65\nGenerator A starts with 8921")
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use error::Error;
+use parser;
+use solution::Solution;
+
+#[derive(Debug)]
+pub struct Day16 {
+    pub seed: Vec<u8>,
+    pub iterations: usize,
+}
+
+impl Default for Day16 {
+    fn default() -> Day16 {
+        Day16 {
+            seed: (b'a'..b'q').collect(),
+            iterations: 1_000_000_000,
+        }
+    }
+}
+
+impl Solution for Day16 {
+    type Input = Vec<Move>;
+    type A = String;
+    type B = String;
+
+    fn parse(&self, input: &str) -> Result<Vec<Move>, Error> {
+        parse(input, &self.seed)
+    }
+
+    fn part_a(&self, moves: &Vec<Move>) -> Result<String, Error> {
+        programs_name(run_a(moves, &self.seed))
+    }
+
+    fn part_b(&self, moves: &Vec<Move>) -> Result<String, Error> {
+        programs_name(run_b(moves, &self.seed, self.iterations))
+    }
+}
+
+fn programs_name(programs: Vec<u8>) -> Result<String, Error> {
+    String::from_utf8(programs).map_err(|err| Error::runtime(16, err.to_string()))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Move {
+    Spin(usize),
+    Exchange(usize, usize),
+    Partner(u8, u8),
+}
+
+pub fn run_a(moves: &[Move], seed: &[u8]) -> Vec<u8> {
+    dance(moves, seed)
+}
+
+pub fn run_b(moves: &[Move], seed: &[u8], iterations: usize) -> Vec<u8> {
+    let mut programs = Vec::from(seed);
+
+    let mut cycle_count = 1;
+    programs = dance(moves, &programs);
+
+    while seed != &programs[..] && cycle_count < iterations {
+        programs = dance(moves, &programs);
+        cycle_count += 1;
+    }
+
+    if seed != &programs[..] {
+        return programs;
+    }
+
+    for _ in 0..(iterations % cycle_count) {
+        programs = dance(moves, &programs);
+    }
+
+    programs
+}
+
+/// Moves are checked against `seed`, so that dancing cannot go out of bounds.
+pub fn parse(input: &str, seed: &[u8]) -> Result<Vec<Move>, Error> {
+    let line = parser::single_line(16, input)?;
+
+    line.split(",")
+        .map(|op| {
+            let error = |message| line.error(op, format!("{}: {:?}", message, op));
+            let arguments = op.get(1..).unwrap_or("");
+
+            match op.as_bytes().first() {
+                Some(&b's') => match arguments.parse() {
+                    Ok(x) if x <= seed.len() => Ok(Move::Spin(x)),
+                    _ => Err(error("Invalid spin")),
+                },
+                Some(&b'x') => {
+                    let ab: Vec<Option<usize>> =
+                        arguments.split('/').map(|n| n.parse().ok()).collect();
+                    match *ab.as_slice() {
+                        [Some(a), Some(b)] if a < seed.len() && b < seed.len() => {
+                            Ok(Move::Exchange(a, b))
+                        }
+                        _ => Err(error("Invalid exchange")),
+                    }
+                }
+                Some(&b'p') => match *arguments.as_bytes() {
+                    [a, b'/', b] if seed.contains(&a) && seed.contains(&b) => {
+                        Ok(Move::Partner(a, b))
+                    }
+                    _ => Err(error("Invalid partner")),
+                },
+                _ => Err(error("Unknown move")),
+            }
+        })
+        .collect()
+}
+
+fn dance(moves: &[Move], seed: &[u8]) -> Vec<u8> {
+    let mut programs = Vec::from(seed);
+
+    for &op in moves {
+        match op {
+            Move::Spin(x) => {
+                let x = programs.len() - x;
+                programs.rotate(x);
+            }
+            Move::Exchange(a, b) => programs.swap(a, b),
+            Move::Partner(a, b) => {
+                let a = programs.iter().position(|&v| v == a).unwrap();
+                let b = programs.iter().position(|&v| v == b).unwrap();
+
+                programs.swap(a, b);
+            }
+        };
+    }
+
+    programs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str;
+
+    #[test]
+    fn test_run_a() {
+        let seed: Vec<_> = (b'a'..b'f').collect();
+        let input = "s1,x3/4,pe/b";
+
+        assert_eq!(Ok("baedc"), str::from_utf8(&run_a(&parse(input, &seed).unwrap(), &seed)));
+    }
+
+    #[test]
+    fn test_run_b() {
+        let seed: Vec<_> = (b'a'..b'f').collect();
+        let input = "s1,x3/4,pe/b";
+
+        assert_eq!(Ok("ceadb"), str::from_utf8(&run_b(&parse(input, &seed).unwrap(), &seed, 2)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let seed: Vec<_> = (b'a'..b'f').collect();
+
+        assert_eq!(
+            Err(Error::parse(16, 1, 4, "Invalid exchange: \"x3/5\"").with_source("s1,x3/5,pe/b")),
+            parse("s1,x3/5,pe/b", &seed)
+        );
+        assert!(parse("s6", &seed).is_err());
+        assert!(parse("pe/z", &seed).is_err());
+        assert!(parse("q", &seed).is_err());
+    }
+}
@@ -0,0 +1,80 @@
+use error::Error;
+use parser;
+use solution::Solution;
+
+#[derive(Debug)]
+pub struct Day17 {
+    pub steps_a: usize,
+    pub steps_b: usize,
+}
+
+impl Default for Day17 {
+    fn default() -> Day17 {
+        Day17 {
+            steps_a: 2017 + 1,
+            steps_b: 50_000_000 + 1,
+        }
+    }
+}
+
+impl Solution for Day17 {
+    type Input = usize;
+    type A = usize;
+    type B = usize;
+
+    fn parse(&self, input: &str) -> Result<usize, Error> {
+        let line = parser::single_line(17, input)?;
+        line.parse(line.text.trim(), "a number of steps")
+    }
+
+    fn part_a(&self, input: &usize) -> Result<usize, Error> {
+        Ok(run_a(*input, self.steps_a))
+    }
+
+    fn part_b(&self, input: &usize) -> Result<usize, Error> {
+        Ok(run_b(*input, self.steps_b))
+    }
+}
+
+pub fn run_a(input: usize, steps: usize) -> usize {
+    let mut buffer = vec![0];
+    let mut position = 0;
+
+    for i in 1..steps {
+        position = (position + 1 + input) % i;
+        buffer.insert(position, i);
+    }
+
+    buffer[(position + 1) % steps]
+}
+
+pub fn run_b(input: usize, steps: usize) -> usize {
+    let mut position = 0;
+    let mut position_0 = 0;
+    let mut value_after_0 = 0;
+
+    for i in 1..steps {
+        position = (position + 1 + input) % i;
+
+        if position == position_0 {
+            value_after_0 = i;
+        } else if position < position_0 {
+            position_0 += 1;
+        }
+    }
+
+    value_after_0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_a() {
+        let input = 3;
+        let steps = 2018;
+
+        assert_eq!(638, run_a(input, steps));
+    }
+}
@@ -0,0 +1,391 @@
+use error::Error;
+use solution::Solution;
+use std::collections::VecDeque;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use vm::{self, Cpu, Flow, Instruction, InstructionSet, Registers, State};
+
+#[derive(Debug, Default)]
+pub struct Day18 {
+    /// Runs the programs of part b on their own threads, rather than in turn on one.
+    pub threads: bool,
+}
+
+impl Solution for Day18 {
+    type Input = Vec<Instruction>;
+    type A = i64;
+    type B = u64;
+
+    fn parse(&self, input: &str) -> Result<Vec<Instruction>, Error> {
+        parse(input)
+    }
+
+    fn part_a(&self, program: &Vec<Instruction>) -> Result<i64, Error> {
+        run_a(program)
+    }
+
+    fn part_b(&self, program: &Vec<Instruction>) -> Result<u64, Error> {
+        if self.threads {
+            Ok(run_ring(program, 2)?[1])
+        } else {
+            run_b(program)
+        }
+    }
+}
+
+/// Both parts run the same instructions, only understood differently.
+pub fn parse(input: &str) -> Result<Vec<Instruction>, Error> {
+    vm::assemble::<Duet>(input)
+}
+
+pub fn run_a(program: &[Instruction]) -> Result<i64, Error> {
+    let mut cpu = Cpu::new(program, Sound::default());
+
+    loop {
+        let state = cpu.step()?;
+
+        if let Some(frequency) = cpu.isa.recovered {
+            return Ok(frequency);
+        }
+        if state == State::Halted {
+            return Err(Error::runtime(18, "The program ended before recovering a frequency"));
+        }
+    }
+}
+
+pub fn run_b(program: &[Instruction]) -> Result<u64, Error> {
+    let mut duo = Duo::new(program);
+    while duo.step()? {}
+
+    Ok(duo.cpus[1].isa.sent)
+}
+
+/// The two programs of part b, whose `p` register is their index.
+pub struct Duo<'a> {
+    pub cpus: [Cpu<'a, Duet>; 2],
+}
+
+impl<'a> Duo<'a> {
+    pub fn new(program: &'a [Instruction]) -> Duo<'a> {
+        let mut cpus = [
+            Cpu::new(program, Duet::default()),
+            Cpu::new(program, Duet::default()),
+        ];
+        cpus[1].registers.set(vm::register('p'), 1);
+
+        Duo { cpus }
+    }
+
+    /// Runs an instruction of each program, then hands what it sent to the other one.
+    /// Returns false once both have halted or wait for values which will never come.
+    pub fn step(&mut self) -> Result<bool, Error> {
+        {
+            let (cpu0, cpu1) = self.cpus.split_at_mut(1);
+            let (cpu0, cpu1) = (&mut cpu0[0], &mut cpu1[0]);
+
+            cpu0.step()?;
+            cpu1.isa.inbox.extend(cpu0.isa.outbox.drain(..));
+            cpu1.step()?;
+            cpu0.isa.inbox.extend(cpu1.isa.outbox.drain(..));
+        }
+
+        // A program waiting with values to receive goes on at its next step
+        Ok(self.cpus.iter().any(|cpu| match cpu.state {
+            State::Running => true,
+            State::Waiting => !cpu.isa.inbox.is_empty(),
+            State::Halted => false,
+        }))
+    }
+}
+
+const OPCODES: &[&str] = &["snd", "rcv", "set", "add", "mul", "mod", "jgz"];
+
+/// Runs `programs` copies of `program`, each on its own thread and with its index in `p`, in a
+/// ring where each sends to the next one. Returns how many values each one sent, once all
+/// have halted or wait for values which will never come.
+pub fn run_ring(program: &[Instruction], programs: usize) -> Result<Vec<u64>, Error> {
+    let program = Arc::new(program.to_vec());
+    let (senders, receivers): (Vec<_>, Vec<_>) = (0..programs).map(|_| mpsc::channel()).unzip();
+    let counts = Arc::new(Mutex::new(Counts {
+        blocked: 0,
+        halted: vec![false; programs],
+        pending: 0,
+        deadlocked: false,
+    }));
+
+    let threads: Vec<_> = receivers
+        .into_iter()
+        .enumerate()
+        .map(|(index, receiver)| {
+            let link = Link {
+                index,
+                senders: senders.clone(),
+                receiver,
+                counts: counts.clone(),
+            };
+            let program = program.clone();
+
+            thread::spawn(move || {
+                let sent = link.run(&program);
+                link.halt();
+                sent
+            })
+        })
+        .collect();
+
+    threads
+        .into_iter()
+        .map(|thread| {
+            thread
+                .join()
+                .unwrap_or_else(|_| Err(Error::runtime(18, "A program panicked")))
+        })
+        .collect()
+}
+
+/// What the programs of a ring are doing, to tell when they are deadlocked.
+#[derive(Debug)]
+struct Counts {
+    /// Programs waiting for a value.
+    blocked: usize,
+    halted: Vec<bool>,
+    /// Values sent to a program which has not received them yet.
+    pending: usize,
+    deadlocked: bool,
+}
+
+/// Connects a program of a ring to the others: it receives `None` once they are deadlocked.
+struct Link {
+    index: usize,
+    senders: Vec<Sender<Option<i64>>>,
+    receiver: Receiver<Option<i64>>,
+    counts: Arc<Mutex<Counts>>,
+}
+
+impl Link {
+    fn run(&self, program: &[Instruction]) -> Result<u64, Error> {
+        let mut cpu = Cpu::new(program, Duet::default());
+        cpu.registers.set(vm::register('p'), self.index as i64);
+
+        loop {
+            let state = cpu.step()?;
+            for value in cpu.isa.outbox.drain(..) {
+                self.send(value);
+            }
+
+            match state {
+                State::Running => {}
+                State::Waiting => match self.receive() {
+                    Some(value) => cpu.isa.inbox.push_back(value),
+                    None => break,
+                },
+                State::Halted => break,
+            }
+        }
+
+        Ok(cpu.isa.sent)
+    }
+
+    fn send(&self, value: i64) {
+        let next = (self.index + 1) % self.senders.len();
+        let mut counts = self.counts.lock().unwrap();
+
+        // What is sent to a halted program is lost
+        if !counts.halted[next] {
+            counts.pending += 1;
+            self.senders[next].send(Some(value)).ok();
+        }
+    }
+
+    /// Waits for a value, unless every other program is blocked or halted too.
+    fn receive(&self) -> Option<i64> {
+        if let Ok(value) = self.receiver.try_recv() {
+            if value.is_some() {
+                self.counts.lock().unwrap().pending -= 1;
+            }
+            return value;
+        }
+
+        {
+            let mut counts = self.counts.lock().unwrap();
+            counts.blocked += 1;
+            self.check(&mut counts);
+        }
+
+        let value = self.receiver.recv().unwrap_or(None);
+        let mut counts = self.counts.lock().unwrap();
+        counts.blocked -= 1;
+        if value.is_some() {
+            counts.pending -= 1;
+        }
+
+        value
+    }
+
+    /// Drops the values which will never be received, then wakes the others if they are
+    /// deadlocked.
+    fn halt(&self) {
+        let mut counts = self.counts.lock().unwrap();
+        if counts.halted[self.index] {
+            return;
+        }
+
+        counts.halted[self.index] = true;
+        counts.pending -= self.receiver.try_iter().filter(Option::is_some).count();
+        self.check(&mut counts);
+    }
+
+    /// Sends `None` to every program once none can go on, which they all receive as they are
+    /// blocked.
+    fn check(&self, counts: &mut Counts) {
+        let halted = counts.halted.iter().filter(|&&halted| halted).count();
+        let stuck = counts.blocked + halted == counts.halted.len();
+
+        if !counts.deadlocked && counts.pending == 0 && stuck {
+            counts.deadlocked = true;
+            for sender in &self.senders {
+                sender.send(None).ok();
+            }
+        }
+    }
+}
+
+/// Instructions as understood in part a: `snd` plays a sound, `rcv` recovers the last one.
+#[derive(Debug, Default)]
+pub struct Sound {
+    pub played: Option<i64>,
+    pub recovered: Option<i64>,
+}
+
+impl InstructionSet for Sound {
+    const DAY: u8 = 18;
+    const REGISTERS: u8 = 26;
+
+    const OPCODES: &'static [&'static str] = OPCODES;
+
+    fn execute(&mut self, registers: &mut Registers, instruction: &Instruction) -> Option<Flow> {
+        match *instruction {
+            Instruction::Snd(x) => self.played = Some(registers.eval(x)),
+            Instruction::Rcv(r) => if registers.get(r) != 0 && self.played.is_some() {
+                self.recovered = self.played;
+            },
+            _ => return None,
+        }
+
+        Some(Flow::Next)
+    }
+
+    fn finished(&self) -> bool {
+        self.recovered.is_some()
+    }
+}
+
+/// Instructions as understood in part b: `snd` sends to the other program, through `outbox`,
+/// and `rcv` waits for a value of `inbox`.
+#[derive(Debug, Default)]
+pub struct Duet {
+    pub inbox: VecDeque<i64>,
+    pub outbox: VecDeque<i64>,
+    pub sent: u64,
+}
+
+impl InstructionSet for Duet {
+    const DAY: u8 = 18;
+    const REGISTERS: u8 = 26;
+
+    const OPCODES: &'static [&'static str] = OPCODES;
+
+    fn execute(&mut self, registers: &mut Registers, instruction: &Instruction) -> Option<Flow> {
+        match *instruction {
+            Instruction::Snd(x) => {
+                self.outbox.push_back(registers.eval(x));
+                self.sent += 1;
+            }
+            Instruction::Rcv(r) => match self.inbox.pop_front() {
+                Some(value) => registers.set(r, value),
+                None => return Some(Flow::Wait),
+            },
+            _ => return None,
+        }
+
+        Some(Flow::Next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_a() {
+        let input = "set a 1
+add a 2
+mul a a
+mod a 5
+snd a
+set a 0
+rcv a
+jgz a -1
+set a 1
+jgz a -2";
+
+        assert_eq!(Ok(4), run_a(&parse(input).unwrap()));
+    }
+
+    #[test]
+    fn test_run_b() {
+        let input = "snd 1
+snd 2
+snd p
+rcv a
+rcv b
+rcv c
+rcv d";
+
+        assert_eq!(Ok(3), run_b(&parse(input).unwrap()));
+        assert_eq!(Ok(vec![3, 3]), run_ring(&parse(input).unwrap(), 2));
+        assert_eq!(Ok(vec![3, 3, 3]), run_ring(&parse(input).unwrap(), 3));
+    }
+
+    #[test]
+    fn test_duo_receives_after_other_halted() {
+        // Program 1 sends and halts while program 0 waits, which then receives the value
+        let program = parse("jgz p 2\nrcv a\nsnd 7").unwrap();
+        let mut duo = Duo::new(&program);
+        while duo.step().unwrap() {}
+
+        assert_eq!([1, 1], [duo.cpus[0].isa.sent, duo.cpus[1].isa.sent]);
+        assert_eq!(Ok(vec![1, 1]), run_ring(&program, 2));
+    }
+
+    #[test]
+    fn test_run_ring_halted() {
+        // The last program halts without receiving, the others wait for one value too many
+        let input = "snd p
+set b p
+add b -1
+jgz b 3
+rcv a
+rcv a";
+        let program = parse(input).unwrap();
+
+        assert_eq!(Ok(vec![1, 1, 1]), run_ring(&program, 3));
+        assert_eq!(Ok(vec![1]), run_ring(&program, 1));
+        assert_eq!(Ok(vec![1]), run_ring(&parse("snd 1\nrcv a").unwrap(), 1));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err(Error::parse(18, 2, 1, "Unknown instruction: jnz").with_source("jnz a -1")),
+            parse("set a 1\njnz a -1")
+        );
+        assert_eq!(
+            Err(Error::parse(18, 1, 5, "Expected a register: \"1\"").with_source("set 1 a")),
+            parse("set 1 a")
+        );
+        assert!(parse("add a").is_err());
+        assert!(run_a(&parse("set a 1").unwrap()).is_err());
+    }
+}
@@ -0,0 +1,195 @@
+use error::Error;
+use parser;
+use solution::Solution;
+use std::collections::HashMap;
+
+#[derive(Debug, Default)]
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Map;
+    type A = String;
+    type B = usize;
+
+    fn parse(&self, input: &str) -> Result<Map, Error> {
+        parse(input)
+    }
+
+    fn part_a(&self, map: &Map) -> Result<String, Error> {
+        run_a(map)
+    }
+
+    fn part_b(&self, map: &Map) -> Result<usize, Error> {
+        run_b(map)
+    }
+}
+
+pub type Position = (usize, usize);
+
+/// The paths of the diagram by position, and where the path starts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Map {
+    pub paths: HashMap<Position, char>,
+    pub start: Position,
+}
+
+pub fn run_a(map: &Map) -> Result<String, Error> {
+    Ok(walk(map)?.0)
+}
+
+pub fn run_b(map: &Map) -> Result<usize, Error> {
+    Ok(walk(map)?.1)
+}
+
+fn walk(map: &Map) -> Result<(String, usize), Error> {
+    let mut position = map.start;
+
+    let mut direction = Direction::Down;
+    let mut count = 0;
+    let mut word = String::new();
+
+    while let Some(&path) = map.paths.get(&position) {
+        match path {
+            path if path.is_ascii_alphabetic() => {
+                word.push(path);
+                position = next_step(position, &direction);
+            }
+            '|' | '-' => {
+                position = next_step(position, &direction);
+            }
+            '+' => {
+                let choices = choose_turn(position, &direction);
+                if map.paths.contains_key(&(choices.0).0) {
+                    position = (choices.0).0;
+                    direction = (choices.0).1;
+                } else {
+                    position = (choices.1).0;
+                    direction = (choices.1).1;
+                }
+            }
+            _ => {
+                return Err(Error::runtime(
+                    19,
+                    format!("Unknown path: {:?}, at: {:?}", path, position),
+                ))
+            }
+        }
+        count += 1;
+    }
+
+    Ok((word, count))
+}
+
+#[derive(Debug)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+fn next_step(position: Position, direction: &Direction) -> Position {
+    match *direction {
+        // Stepping out of the map wraps around to a position which is not in the map either
+        Direction::Up => (position.0.wrapping_sub(1), position.1),
+        Direction::Down => (position.0 + 1, position.1),
+        Direction::Left => (position.0, position.1.wrapping_sub(1)),
+        Direction::Right => (position.0, position.1 + 1),
+    }
+}
+
+fn choose_turn(
+    position: Position,
+    direction: &Direction,
+) -> ((Position, Direction), (Position, Direction)) {
+    let choices = turn(direction);
+
+    (
+        (next_step(position, &choices.0), choices.0),
+        (next_step(position, &choices.1), choices.1),
+    )
+}
+
+fn turn(direction: &Direction) -> (Direction, Direction) {
+    match *direction {
+        Direction::Up | Direction::Down => (Direction::Left, Direction::Right),
+        Direction::Left | Direction::Right => (Direction::Up, Direction::Down),
+    }
+}
+
+pub fn parse(input: &str) -> Result<Map, Error> {
+    let mut paths = HashMap::new();
+    let mut start = None;
+
+    for line in parser::lines(19, input) {
+        let i = line.number - 1;
+
+        for (j, b) in line.text.chars().enumerate() {
+            match b {
+                path if path.is_ascii_alphabetic() => {
+                    paths.insert((i, j), b);
+                }
+                '-' | '|' | '+' => {
+                    paths.insert((i, j), b);
+                }
+                ' ' => {}
+                _ => return Err(line.error_at(j + 1, format!("Unknown path: {:?}", b))),
+            };
+        }
+
+        if i == 0 {
+            start = line.text.chars().position(|b| b == '|');
+            if start.is_none() {
+                return Err(line.error_at(1, "Cannot find the start of the path: '|'"));
+            }
+        }
+    }
+
+    match start {
+        Some(start) => Ok(Map {
+            paths,
+            start: (0, start),
+        }),
+        None => Err(Error::parse(19, 1, 1, "Cannot find the start of the path: '|'")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_a() {
+        let input = "     |
+     |  +--+
+     A  |  C
+ F---|----E|--+
+     |  |  |  D
+     +B-+  +--+
+";
+
+        assert_eq!(Ok(String::from("ABCDEF")), run_a(&parse(input).unwrap()));
+    }
+
+    #[test]
+    fn test_run_b() {
+        let input = "     |
+     |  +--+
+     A  |  C
+ F---|----E|--+
+     |  |  |  D
+     +B-+  +--+
+";
+
+        assert_eq!(Ok(38), run_b(&parse(input).unwrap()));
+    }
+
+    #[test]
+    fn test_missing_start() {
+        assert_eq!(
+            Err(Error::parse(19, 1, 1, "Cannot find the start of the path: '|'")
+                .with_source("  +--A")),
+            parse("  +--A")
+        );
+    }
+}
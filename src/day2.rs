@@ -0,0 +1,89 @@
+use error::Error;
+use parser;
+use solution::Solution;
+
+#[derive(Debug, Default)]
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<u32>>;
+    type A = u32;
+    type B = u32;
+
+    fn parse(&self, input: &str) -> Result<Vec<Vec<u32>>, Error> {
+        parse(input)
+    }
+
+    fn part_a(&self, rows: &Vec<Vec<u32>>) -> Result<u32, Error> {
+        Ok(run_a(rows))
+    }
+
+    fn part_b(&self, rows: &Vec<Vec<u32>>) -> Result<u32, Error> {
+        run_b(rows)
+    }
+}
+
+/// Rows of the spreadsheet.
+pub fn parse(input: &str) -> Result<Vec<Vec<u32>>, Error> {
+    parser::lines(2, input)
+        .map(|line| line.words().map(|n| line.parse(n, "a number")).collect())
+        .collect()
+}
+
+pub fn run_a(rows: &[Vec<u32>]) -> u32 {
+    rows.iter()
+        .map(|vals| {
+            let min = *vals.iter().min().unwrap_or(&0u32);
+            let max = *vals.iter().max().unwrap_or(&0u32);
+            max - min
+        })
+        .sum()
+}
+
+pub fn run_b(rows: &[Vec<u32>]) -> Result<u32, Error> {
+    rows.iter()
+        .enumerate()
+        .map(|(i, vals)| {
+            even_division(vals).ok_or_else(|| {
+                Error::runtime(2, format!("line {}: no number evenly divides another", i + 1))
+            })
+        })
+        .sum()
+}
+
+fn even_division(vals: &[u32]) -> Option<u32> {
+    for x in 0..vals.len() {
+        for y in 0..vals.len() {
+            if x != y && vals[y] != 0 && vals[x] % vals[y] == 0 {
+                return Some(vals[x] / vals[y]);
+            }
+        }
+    }
+    // "the only two numbers in each row where one evenly divides the other"
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_a() {
+        let input = "5	1	9	5
+7	5	3
+2	4	6	8";
+
+        assert_eq!(18, run_a(&parse(input).unwrap()));
+    }
+
+    #[test]
+    fn test_run_b() {
+        let input = "5	9	2	8
+9	4	7	3
+3	8	6	5";
+
+        assert_eq!(Ok(9), run_b(&parse(input).unwrap()));
+        assert!(run_b(&parse("3\t5").unwrap()).is_err());
+        assert!(parse("3\tx").is_err());
+    }
+}
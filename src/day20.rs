@@ -0,0 +1,156 @@
+use error::Error;
+use parser::{self, Line};
+use solution::Solution;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+#[derive(Debug, Default)]
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<Vec<i64>>;
+    type A = usize;
+    type B = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<Vec<i64>>, Error> {
+        parse(input)
+    }
+
+    fn part_a(&self, particules: &Vec<Vec<i64>>) -> Result<usize, Error> {
+        run_a(particules)
+    }
+
+    fn part_b(&self, particules: &Vec<Vec<i64>>) -> Result<usize, Error> {
+        Ok(run_b(particules))
+    }
+}
+
+pub fn run_a(particules: &[Vec<i64>]) -> Result<usize, Error> {
+    particules
+        .iter()
+        .enumerate()
+        .map(|(i, particule)| {
+            (
+                i,
+                manhattan_distance(&particule[6..9]),
+                manhattan_distance(&particule[0..3])
+                    .cmp(&manhattan_distance(&next(particule)[0..3])),
+            )
+        })
+        .min_by(|a, b| {
+            let compare_acceleration = (a.1).cmp(&b.1);
+            if compare_acceleration == Ordering::Equal {
+                (a.2).cmp(&b.2)
+            } else {
+                compare_acceleration
+            }
+        })
+        .map(|closest| closest.0)
+        .ok_or_else(|| Error::runtime(20, "No particule"))
+}
+
+pub fn run_b(particules: &[Vec<i64>]) -> usize {
+    let mut particules: HashMap<_, _> = particules.iter().cloned().enumerate().collect();
+
+    for _ in 0..39 {
+        let mut positions: HashMap<Vec<i64>, usize> = HashMap::new();
+
+        for v in particules.values_mut() {
+            next(v).iter().enumerate().for_each(|(i, c)| v[i] = *c);
+            *positions.entry(v[0..3].to_vec()).or_insert(0) += 1;
+        }
+
+        particules.retain(|_, v| positions[&v[0..3]] == 1);
+    }
+
+    particules.len()
+}
+
+/// Position, velocity and acceleration of each particule, flattened.
+pub fn parse(input: &str) -> Result<Vec<Vec<i64>>, Error> {
+    parser::lines(20, input)
+        .map(|line| {
+            let mut vectors = line.split(">,");
+            let mut particule = parse_vector(&line, vectors.token("a position")?, "p")?;
+            particule.extend(parse_vector(&line, vectors.token("a velocity")?, "v")?);
+            particule.extend(parse_vector(&line, vectors.token("an acceleration")?, "a")?);
+            vectors.end()?;
+
+            Ok(particule)
+        })
+        .collect()
+}
+
+/// Parses `<name>=<x,y,z`, the closing `>` being optional as `vector` was split on it.
+fn parse_vector(line: &Line, vector: &str, name: &str) -> Result<Vec<i64>, Error> {
+    let prefix = format!("{}=<", name);
+    if !vector.starts_with(&prefix) {
+        return Err(line.error(vector, format!("expected `{}`", prefix)));
+    }
+
+    let coordinates = vector[prefix.len()..]
+        .trim_right_matches('>')
+        .split(',')
+        .map(|n| line.parse(n.trim(), "a coordinate"))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if coordinates.len() == 3 {
+        Ok(coordinates)
+    } else {
+        Err(line.error(vector, "expected 3 coordinates"))
+    }
+}
+
+fn manhattan_distance(coordinates: &[i64]) -> u64 {
+    coordinates.iter().map(|n| n.abs() as u64).sum()
+}
+
+fn zip_sum(a: &[i64], b: &[i64]) -> Vec<i64> {
+    a.iter().zip(b.iter()).map(|(p, v)| p + v).collect()
+}
+
+fn next(particule: &[i64]) -> Vec<i64> {
+    let position = &particule[0..3];
+    let velocity = &particule[3..6];
+    let acceleration = &particule[6..9];
+
+    let mut velocity = zip_sum(velocity, acceleration);
+    let mut position = zip_sum(position, &velocity);
+
+    position.append(&mut velocity);
+    position.append(&mut acceleration.to_vec());
+
+    position
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_a() {
+        let input = "p=< 3,0,0>, v=< 2,0,0>, a=<-1,0,0>
+p=< 4,0,0>, v=< 0,0,0>, a=<-2,0,0>";
+
+        assert_eq!(Ok(0), run_a(&parse(input).unwrap()));
+    }
+
+    #[test]
+    fn test_run_b() {
+        let input = "p=<-6,0,0>, v=< 3,0,0>, a=< 0,0,0>
+p=<-4,0,0>, v=< 2,0,0>, a=< 0,0,0>
+p=<-2,0,0>, v=< 1,0,0>, a=< 0,0,0>
+p=< 3,0,0>, v=<-1,0,0>, a=< 0,0,0>";
+
+        assert_eq!(1, run_b(&parse(input).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Err(Error::parse(20, 2, 13, "expected 3 coordinates")
+                .with_source("p=<-4,0,0>, v=< 2,0>, a=< 0,0,0>")),
+            parse("p=<-6,0,0>, v=< 3,0,0>, a=< 0,0,0>\np=<-4,0,0>, v=< 2,0>, a=< 0,0,0>")
+        );
+    }
+}
@@ -0,0 +1,215 @@
+use error::Error;
+use parser::{self, Line};
+use itertools::Itertools;
+use solution::Solution;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub struct Day21 {
+    pub seed: String,
+    pub iterations_a: usize,
+    pub iterations_b: usize,
+}
+
+impl Default for Day21 {
+    fn default() -> Day21 {
+        Day21 {
+            seed: ".#.
+..#
+###"
+                .replace('\n', ""),
+            iterations_a: 5,
+            iterations_b: 18,
+        }
+    }
+}
+
+impl Solution for Day21 {
+    type Input = Rules;
+    type A = usize;
+    type B = usize;
+
+    fn parse(&self, input: &str) -> Result<Rules, Error> {
+        parse(input)
+    }
+
+    fn part_a(&self, rules: &Rules) -> Result<usize, Error> {
+        fractal_art(rules, &self.seed, self.iterations_a)
+    }
+
+    fn part_b(&self, rules: &Rules) -> Result<usize, Error> {
+        fractal_art(rules, &self.seed, self.iterations_b)
+    }
+}
+
+/// The enhancement of each pattern, rotated and flipped, as single rows of pixels.
+pub type Rules = HashMap<Vec<u8>, Vec<u8>>;
+
+/// Parses the rules, adding each rotation and flip of their patterns.
+pub fn parse(input: &str) -> Result<Rules, Error> {
+    let mut enhancement_rules = HashMap::new();
+    for line in parser::lines(21, input) {
+        let mut it = line.split("=>");
+        let k = it.token("a pattern")?;
+        let v = it.token("`=>`")?;
+        it.end()?;
+
+        let k = match k.len() {
+            5 => parse_pattern(&line, k, 2)?,
+            _ => parse_pattern(&line, k, 3)?,
+        };
+        let v = parse_pattern(&line, v, if k.len() == 4 { 3 } else { 4 })?;
+
+        for k in rotate_flip(k) {
+            enhancement_rules.insert(k, v.clone());
+        }
+    }
+
+    Ok(enhancement_rules)
+}
+
+pub fn fractal_art(rules: &Rules, seed: &str, iterations: usize) -> Result<usize, Error> {
+    let mut frame = String::from(seed).into_bytes();
+    let mut frame_size = 3;
+    let mut chunk = chunk_size(frame_size);
+
+    for _ in 0..iterations {
+        let next_frame_size = next_frame_size(frame_size, chunk);
+        let mut hidden_frame = vec![0; next_frame_size * next_frame_size];
+
+        for block in generate_chunk_positions(frame_size, chunk) {
+            let view: Vec<_> = (0..chunk)
+                .cartesian_product(0..chunk)
+                .map(|c| block + frame_size * c.0 + c.1)
+                .map(|i| frame[i])
+                .collect();
+
+            let new_view = rules.get(&view).ok_or_else(|| {
+                Error::runtime(
+                    21,
+                    format!("No rule enhances {:?}", String::from_utf8_lossy(&view)),
+                )
+            })?;
+
+            let (row, column) = (block / frame_size / chunk, block % frame_size / chunk);
+            let shift = (row * next_frame_size + column) * (chunk + 1);
+            for i in 0..new_view.len() {
+                hidden_frame[shift + (i % (chunk + 1)) + i / (chunk + 1) * next_frame_size] =
+                    new_view[i];
+            }
+        }
+
+        frame = hidden_frame;
+        chunk = chunk_size(next_frame_size);
+        frame_size = next_frame_size;
+    }
+
+    Ok(bytecount::count(&frame, b'#'))
+}
+
+/// Parses `size` rows of `size` pixels separated by `/`, into a single row.
+fn parse_pattern(line: &Line, pattern: &str, size: usize) -> Result<Vec<u8>, Error> {
+    let rows: Vec<_> = pattern.split('/').collect();
+
+    if rows.len() != size || rows.iter().any(|row| row.len() != size) {
+        return Err(line.error(pattern, format!("expected a {}x{} pattern", size, size)));
+    }
+
+    for (j, c) in pattern.char_indices() {
+        if c != '.' && c != '#' && c != '/' {
+            return Err(line.error(&pattern[j..], format!("expected a pixel, found {:?}", c)));
+        }
+    }
+
+    Ok(pattern.replace('/', "").into_bytes())
+}
+
+fn chunk_size(size: usize) -> usize {
+    if size % 2 == 0 {
+        2
+    } else {
+        3
+    }
+}
+
+fn next_frame_size(frame_size: usize, chunk: usize) -> usize {
+    frame_size / chunk * (chunk + 1)
+}
+
+fn generate_chunk_positions(size: usize, chunk: usize) -> Vec<usize> {
+    (0..size)
+        .step_by(chunk)
+        .cartesian_product((0..size).step_by(chunk))
+        .map(|start| start.0 * size + start.1)
+        .collect()
+}
+
+fn rotate_flip(k: Vec<u8>) -> Vec<Vec<u8>> {
+    let mut keys = vec![k];
+
+    for _ in 0..3 {
+        let new_key = rotate(keys.last().unwrap());
+        keys.push(new_key);
+    }
+
+    let new_key = flip(keys.first().unwrap());
+    keys.push(new_key);
+
+    for _ in 0..3 {
+        let new_key = rotate(keys.last().unwrap());
+        keys.push(new_key);
+    }
+
+    keys
+}
+
+fn rotate(k: &[u8]) -> Vec<u8> {
+    match k.len() {
+        4 => vec![k[2], k[0], k[3], k[1]],
+        9 => vec![k[6], k[3], k[0], k[7], k[4], k[1], k[8], k[5], k[2]],
+        _ => vec![],
+    }
+}
+
+fn flip(k: &[u8]) -> Vec<u8> {
+    match k.len() {
+        4 => vec![k[2], k[3], k[0], k[1]],
+        9 => vec![k[6], k[7], k[8], k[3], k[4], k[5], k[0], k[1], k[2]],
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fractal_art() {
+        let input = "../.# => ##./#../...
+.#./..#/### => #..#/..../..../#..#";
+
+        let seed = ".#.
+..#
+###"
+            .replace('\n', "");
+
+        let iterations = 2;
+        assert_eq!(Ok(12), fractal_art(&parse(input).unwrap(), &seed, iterations));
+    }
+
+    #[test]
+    fn test_fractal_art_errors() {
+        let seed = ".#...####";
+
+        assert!(fractal_art(&parse("../.# => ##./#../...").unwrap(), seed, 1).is_err());
+        assert_eq!(
+            Err(Error::parse(21, 1, 10, "expected a 3x3 pattern").with_source("../.# => ##./#..")),
+            parse("../.# => ##./#..")
+        );
+        assert_eq!(
+            Err(Error::parse(21, 1, 2, "expected a pixel, found 'x'")
+                .with_source(".x/.# => ##./#../...")),
+            parse(".x/.# => ##./#../...")
+        );
+    }
+}
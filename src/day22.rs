@@ -0,0 +1,266 @@
+use error::Error;
+use parser;
+use solution::Solution;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub struct Day22 {
+    pub bursts_a: usize,
+    pub bursts_b: usize,
+}
+
+impl Default for Day22 {
+    fn default() -> Day22 {
+        Day22 {
+            bursts_a: 10_000,
+            bursts_b: 10_000_000,
+        }
+    }
+}
+
+impl Solution for Day22 {
+    type Input = Grid;
+    type A = usize;
+    type B = usize;
+
+    fn parse(&self, input: &str) -> Result<Grid, Error> {
+        parse(input)
+    }
+
+    fn part_a(&self, grid: &Grid) -> Result<usize, Error> {
+        Ok(run_a(grid, self.bursts_a))
+    }
+
+    fn part_b(&self, grid: &Grid) -> Result<usize, Error> {
+        Ok(run_b(grid, self.bursts_b))
+    }
+}
+
+/// The state of the nodes of the map, and the middle node where the carrier starts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid {
+    pub nodes: HashMap<(i64, i64), Flag>,
+    pub start: (i64, i64),
+}
+
+pub fn run_a(grid: &Grid, bursts: usize) -> usize {
+    let mut nodes = grid.nodes.clone();
+    let mut carrier = VirusCarrier::new(grid.start);
+
+    for _ in 0..bursts {
+        carrier.next_a(&mut nodes);
+    }
+
+    carrier.infection_count
+}
+
+pub fn run_b(grid: &Grid, bursts: usize) -> usize {
+    let mut nodes = grid.nodes.clone();
+    let mut carrier = VirusCarrier::new(grid.start);
+
+    for _ in 0..bursts {
+        carrier.next_b(&mut nodes);
+    }
+
+    carrier.infection_count
+}
+
+pub fn parse(input: &str) -> Result<Grid, Error> {
+    let middle = (
+        (input.lines().count() / 2) as i64,
+        (input.lines().next().unwrap_or("").chars().count() / 2) as i64,
+    );
+
+    let mut nodes = HashMap::new();
+    for line in parser::lines(22, input) {
+        let i = line.number - 1;
+
+        for (j, c) in line.text.chars().enumerate() {
+            match c {
+                '#' => {
+                    nodes.insert((i as i64, j as i64), Flag::Infected);
+                }
+                '.' => {
+                    nodes.insert((i as i64, j as i64), Flag::Clean);
+                }
+                'W' => {
+                    nodes.insert((i as i64, j as i64), Flag::Weakened);
+                }
+                'F' => {
+                    nodes.insert((i as i64, j as i64), Flag::Flagged);
+                }
+                _ => return Err(line.error_at(j + 1, format!("Unknown node state: {:?}", c))),
+            };
+        }
+    }
+
+    Ok(Grid {
+        nodes,
+        start: middle,
+    })
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Flag {
+    Clean,
+    Weakened,
+    Infected,
+    Flagged,
+}
+
+fn next_flag(flag: &Flag) -> Flag {
+    match *flag {
+        Flag::Clean => Flag::Weakened,
+        Flag::Weakened => Flag::Infected,
+        Flag::Infected => Flag::Flagged,
+        Flag::Flagged => Flag::Clean,
+    }
+}
+
+fn turn_its_left(direction: &Direction) -> Direction {
+    match *direction {
+        Direction::Up => Direction::Left,
+        Direction::Left => Direction::Down,
+        Direction::Down => Direction::Right,
+        Direction::Right => Direction::Up,
+    }
+}
+
+fn turn_its_right(direction: &Direction) -> Direction {
+    match *direction {
+        Direction::Up => Direction::Right,
+        Direction::Right => Direction::Down,
+        Direction::Down => Direction::Left,
+        Direction::Left => Direction::Up,
+    }
+}
+
+fn reverse_direction(direction: &Direction) -> Direction {
+    match *direction {
+        Direction::Up => Direction::Down,
+        Direction::Down => Direction::Up,
+        Direction::Left => Direction::Right,
+        Direction::Right => Direction::Left,
+    }
+}
+
+#[derive(Debug)]
+struct VirusCarrier {
+    facing: Direction,
+    position: (i64, i64),
+    infection_count: usize,
+}
+
+impl VirusCarrier {
+    fn new(position: (i64, i64)) -> VirusCarrier {
+        VirusCarrier {
+            facing: Direction::Up,
+            position,
+            infection_count: 0,
+        }
+    }
+
+    fn next_a(&mut self, nodes: &mut HashMap<(i64, i64), Flag>) {
+        let flag = nodes.entry(self.position).or_insert(Flag::Clean);
+
+        match *flag {
+            Flag::Clean => {
+                self.facing = turn_its_left(&self.facing);
+                self.infection_count += 1;
+                *flag = Flag::Infected;
+            }
+            Flag::Infected => {
+                self.facing = turn_its_right(&self.facing);
+                *flag = Flag::Clean;
+            }
+            _ => {}
+        };
+
+        match self.facing {
+            Direction::Up => self.position.0 -= 1,
+            Direction::Down => self.position.0 += 1,
+            Direction::Left => self.position.1 -= 1,
+            Direction::Right => self.position.1 += 1,
+        };
+    }
+
+    fn next_b(&mut self, nodes: &mut HashMap<(i64, i64), Flag>) {
+        let flag = nodes.entry(self.position).or_insert(Flag::Clean);
+
+        match *flag {
+            Flag::Clean => {
+                self.facing = turn_its_left(&self.facing);
+            }
+            Flag::Weakened => {
+                self.infection_count += 1;
+            }
+            Flag::Infected => {
+                self.facing = turn_its_right(&self.facing);
+            }
+            Flag::Flagged => {
+                self.facing = reverse_direction(&self.facing);
+            }
+        };
+
+        *flag = next_flag(flag);
+
+        match self.facing {
+            Direction::Up => self.position.0 -= 1,
+            Direction::Down => self.position.0 += 1,
+            Direction::Left => self.position.1 -= 1,
+            Direction::Right => self.position.1 += 1,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_a() {
+        let input = "..#
+#..
+...";
+        let grid = parse(input).unwrap();
+
+        let bursts = 7;
+        assert_eq!(5, run_a(&grid, bursts));
+
+        let bursts = 70;
+        assert_eq!(41, run_a(&grid, bursts));
+
+        let bursts = 10_000;
+        assert_eq!(5587, run_a(&grid, bursts));
+    }
+
+    #[test]
+    fn test_run_b() {
+        let input = "..#
+#..
+...";
+        let grid = parse(input).unwrap();
+
+        let bursts = 100;
+        assert_eq!(26, run_b(&grid, bursts));
+
+        let bursts = 10_000_000;
+        assert_eq!(2511944, run_b(&grid, bursts));
+    }
+
+    #[test]
+    fn test_unknown_node_state() {
+        assert_eq!(
+            Err(Error::parse(22, 2, 3, "Unknown node state: 'x'").with_source("#.x")),
+            parse("..#\n#.x\n...")
+        );
+    }
+}
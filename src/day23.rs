@@ -0,0 +1,110 @@
+use error::Error;
+use primal;
+use solution::Solution;
+use vm::{self, Cpu, Flow, Instruction, InstructionSet, Registers};
+
+#[derive(Debug, Default)]
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Vec<Instruction>;
+    type A = u64;
+    type B = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<Instruction>, Error> {
+        vm::assemble::<Coprocessor>(input)
+    }
+
+    fn part_a(&self, program: &Vec<Instruction>) -> Result<u64, Error> {
+        run_a(program)
+    }
+
+    fn part_b(&self, _program: &Vec<Instruction>) -> Result<usize, Error> {
+        Ok(run_b())
+    }
+}
+
+pub fn run_a(program: &[Instruction]) -> Result<u64, Error> {
+    let mut cpu = Cpu::new(program, Coprocessor::default());
+    cpu.run()?;
+
+    Ok(cpu.isa.mul_count)
+}
+
+pub fn run_b() -> usize {
+    (0..1_001)
+        .filter(|&n| !primal::is_prime(107_900 + n * 17))
+        .count()
+
+    // loop {
+    //     let mut f = 1;
+    //     let mut d = 2;
+    //     let mut g = 0;
+    //     loop {
+    //         let mut e = 2;
+    //         loop {
+    //             g = d * e - b;
+    //             if g == 0 { // b == d*e i.e. b is not prime
+    //                 f = 0;
+    //             }
+    //             e += 1;
+    //             g = e - b;
+    //             if g == 0 { // for e in (2..b)
+    //                 break;
+    //             }
+    //         }
+    //         d += 1;
+    //         g = d - b;
+    //         if g == 0 { // for d in (2..b)
+    //             break;
+    //         }
+    //     }
+    //     if f == 0 { // count not prime
+    //         h += 1;
+    //     }
+    //     g = b - c;
+    //     if g == 0 { // for i in (b..(c+1) + 17)
+    //         return h;
+    //     }
+    //     b += 17;
+    // }
+}
+
+/// Instructions of the coprocessor, counting the `mul` it runs.
+#[derive(Debug, Default)]
+pub struct Coprocessor {
+    pub mul_count: u64,
+}
+
+impl InstructionSet for Coprocessor {
+    const DAY: u8 = 23;
+    const REGISTERS: u8 = 8;
+
+    const OPCODES: &'static [&'static str] = &["set", "sub", "mul", "jnz"];
+
+    fn execute(&mut self, _registers: &mut Registers, instruction: &Instruction) -> Option<Flow> {
+        if let Instruction::Mul(..) = *instruction {
+            self.mul_count += 1;
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_a() {
+        let input = "set b 3
+mul b b
+sub b 1
+jnz b -1
+mul a 2";
+
+        assert_eq!(Ok(2), run_a(&vm::assemble::<Coprocessor>(input).unwrap()));
+        assert!(vm::assemble::<Coprocessor>("mul a 2\nsnd a").is_err());
+        assert!(vm::assemble::<Coprocessor>("mul i 2").is_err());
+    }
+}
@@ -0,0 +1,150 @@
+use error::Error;
+use parser;
+use solution::Solution;
+
+#[derive(Debug, Default)]
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<(usize, usize)>;
+    type A = usize;
+    type B = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<(usize, usize)>, Error> {
+        parse(input)
+    }
+
+    fn part_a(&self, components: &Vec<(usize, usize)>) -> Result<usize, Error> {
+        Ok(run_a(components))
+    }
+
+    fn part_b(&self, components: &Vec<(usize, usize)>) -> Result<usize, Error> {
+        Ok(run_b(components))
+    }
+}
+
+type ScoreLength = (usize, usize);
+
+pub fn run_a(components: &[(usize, usize)]) -> usize {
+    let mut bridges = make_bridges(components);
+
+    let logic = |a: ScoreLength, b: ScoreLength| -> ScoreLength {
+        if a.0 > b.0 {
+            a
+        } else {
+            b
+        }
+    };
+
+    chain(0, 0, &mut bridges, logic).0
+}
+
+pub fn run_b(components: &[(usize, usize)]) -> usize {
+    let mut bridges = make_bridges(components);
+
+    let logic = |a: ScoreLength, b: ScoreLength| -> ScoreLength {
+        if a.1 > b.1 {
+            a
+        } else if a.1 < b.1 {
+            b
+        } else if a.0 > b.0 {
+            a
+        } else {
+            b
+        }
+    };
+
+    chain(0, 0, &mut bridges, logic).0
+}
+
+fn chain(
+    seed: usize,
+    length: usize,
+    bridges: &mut Vec<(usize, usize, bool)>,
+    logic: fn(ScoreLength, ScoreLength) -> ScoreLength,
+) -> ScoreLength {
+    let candidates: Vec<_> = bridges
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &(a, b, free))| {
+            if free && seed == a {
+                Some((i, b))
+            } else if free && seed == b {
+                Some((i, a))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    candidates.iter().fold((0, length), |max, &(i, next_seed)| {
+        if let Some(&(a, b, _)) = bridges.get(i) {
+            bridges[i].2 = false;
+            let score = chain(next_seed, length + 1, bridges, logic);
+            bridges[i].2 = true;
+            return logic((a + b + score.0, score.1), max);
+        }
+        (0, length)
+    })
+}
+
+/// The components, each free to be used.
+fn make_bridges(components: &[(usize, usize)]) -> Vec<(usize, usize, bool)> {
+    components.iter().map(|&(a, b)| (a, b, true)).collect()
+}
+
+/// The ports of each component.
+pub fn parse(input: &str) -> Result<Vec<(usize, usize)>, Error> {
+    parser::lines(24, input)
+        .map(|line| {
+            let mut ports = line.split("/");
+            let a = ports.parse("a port")?;
+            let b = ports.parse("a port")?;
+            ports.end()?;
+
+            Ok((a, b))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_a() {
+        let input = "0/2
+2/2
+2/3
+3/4
+3/5
+0/1
+10/1
+9/10";
+
+        assert_eq!(31, run_a(&parse(input).unwrap()));
+    }
+
+    #[test]
+    fn test_run_b() {
+        let input = "0/2
+2/2
+2/3
+3/4
+3/5
+0/1
+10/1
+9/10";
+
+        assert_eq!(19, run_b(&parse(input).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err(Error::parse(24, 2, 3, "expected a port, found \"x\"").with_source("2/x")),
+            parse("0/2\n2/x")
+        );
+        assert!(parse("0/2/3").is_err());
+    }
+}
@@ -0,0 +1,236 @@
+use error::Error;
+use parser::{self, Line, Lines};
+use solution::Solution;
+use std::collections::VecDeque;
+
+#[derive(Debug, Default)]
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Turing;
+    type A = usize;
+    type B = usize;
+
+    fn parse(&self, input: &str) -> Result<Turing, Error> {
+        parse(input)
+    }
+
+    fn part_a(&self, turing: &Turing) -> Result<usize, Error> {
+        run_a(turing)
+    }
+
+    fn part_b(&self, _turing: &Turing) -> Result<usize, Error> {
+        Err(Error::runtime(25, "There is no part b"))
+    }
+}
+
+/// The Turing machine of the blueprint: its first state, the number of steps before the
+/// checksum, and the transitions of each state for a 0 and a 1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Turing {
+    pub start: u8,
+    pub steps: usize,
+    pub transitions: Vec<(Transistion, Transistion)>,
+}
+
+pub fn parse(input: &str) -> Result<Turing, Error> {
+    let mut blueprint = Blueprint::new(input);
+    let (start, steps) = make_initial_state(&mut blueprint)?;
+    let transitions = make_turing_machine(&mut blueprint)?;
+
+    Ok(Turing {
+        start,
+        steps,
+        transitions,
+    })
+}
+
+pub fn run_a(turing: &Turing) -> Result<usize, Error> {
+    let mut state = turing.start;
+    let mut tape = VecDeque::new();
+    tape.push_back(0);
+
+    let mut pointer = 0;
+
+    for _ in 0..turing.steps {
+        let current_value = tape[pointer];
+        let &(value_0, value_1) = turing
+            .transitions
+            .get((state - b'A') as usize)
+            .ok_or_else(|| Error::runtime(25, format!("Unknown state: {}", state as char)))?;
+
+        let (write_value, is_next_left, next_state) =
+            if current_value == 0 { value_0 } else { value_1 };
+
+        tape[pointer] = write_value;
+
+        if is_next_left {
+            if pointer == 0 {
+                tape.push_front(0);
+            } else {
+                pointer -= 1;
+            }
+        } else {
+            if pointer == tape.len() - 1 {
+                tape.push_back(0);
+            }
+            pointer += 1;
+        }
+
+        state = next_state;
+    }
+
+    Ok(tape.into_iter().sum())
+}
+
+/// Lines of the blueprint, read one after the other.
+struct Blueprint<'a> {
+    lines: Lines<'a>,
+    line: Line<'a>,
+}
+
+impl<'a> Blueprint<'a> {
+    fn new(input: &'a str) -> Blueprint<'a> {
+        Blueprint {
+            lines: parser::lines(25, input),
+            line: Line {
+                day: 25,
+                number: 0,
+                text: "",
+            },
+        }
+    }
+
+    /// Moves to the next line, an empty one past the end of the blueprint.
+    fn next_line(&mut self) -> Option<Line<'a>> {
+        let next = self.lines.next();
+        self.line = next.unwrap_or(Line {
+            number: self.line.number + 1,
+            text: "",
+            ..self.line
+        });
+        next
+    }
+
+    /// Word `nth` of the next line, without its trailing punctuation.
+    fn word(&mut self, nth: usize, expected: &str) -> Result<&'a str, Error> {
+        self.next_line();
+        let line = self.line;
+
+        line.words()
+            .nth(nth)
+            .map(|word| word.trim_right_matches(|c| c == '.' || c == ':'))
+            .and_then(|word| if word.is_empty() { None } else { Some(word) })
+            .ok_or_else(|| line.error_at_end(format!("expected {}", expected)))
+    }
+
+    fn error(&self, word: &str, message: String) -> Error {
+        self.line.error(word, format!("{}: {:?}", message, word))
+    }
+
+    fn state(&mut self, nth: usize) -> Result<u8, Error> {
+        let state = self.word(nth, "a state")?;
+
+        match state.as_bytes() {
+            &[state] if state >= b'A' && state <= b'Z' => Ok(state),
+            _ => Err(self.error(state, String::from("Invalid state"))),
+        }
+    }
+}
+
+fn make_initial_state(blueprint: &mut Blueprint) -> Result<(u8, usize), Error> {
+    let seed_state = blueprint.state(3)?;
+
+    let checksum_iteration = blueprint.word(5, "a number of steps")?;
+    let checksum_iteration = checksum_iteration
+        .parse()
+        .map_err(|_| blueprint.error(checksum_iteration, String::from("Invalid number of steps")))?;
+
+    Ok((seed_state, checksum_iteration))
+}
+
+/// The value to write, whether to move left, and the next state.
+pub type Transistion = (usize, bool, u8);
+
+fn make_turing_machine(
+    blueprint: &mut Blueprint,
+) -> Result<Vec<(Transistion, Transistion)>, Error> {
+    let mut turing = Vec::new();
+
+    while let Some(_) = blueprint.next_line() {
+        blueprint.next_line();
+
+        let mut state = ((0, false, 0), (0, false, 0));
+        for i in 0..2 {
+            blueprint.next_line();
+
+            let current_value = match blueprint.word(4, "a value to write")? {
+                "0" => 0,
+                "1" => 1,
+                value => return Err(blueprint.error(value, String::from("Invalid value"))),
+            };
+
+            let is_next_left = match blueprint.word(6, "a direction")? {
+                "left" => true,
+                "right" => false,
+                direction => {
+                    return Err(blueprint.error(direction, String::from("Invalid direction")))
+                }
+            };
+
+            let next_state = blueprint.state(4)?;
+
+            if i == 0 {
+                state.0 = (current_value, is_next_left, next_state);
+            } else {
+                state.1 = (current_value, is_next_left, next_state);
+            }
+        }
+
+        turing.push(state);
+    }
+
+    Ok(turing)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_a() {
+        let input = "Begin in state A.
+Perform a diagnostic checksum after 6 steps.
+
+In state A:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the right.
+    - Continue with state B.
+  If the current value is 1:
+    - Write the value 0.
+    - Move one slot to the left.
+    - Continue with state B.
+
+In state B:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the left.
+    - Continue with state A.
+  If the current value is 1:
+    - Write the value 1.
+    - Move one slot to the right.
+    - Continue with state A.";
+
+        assert_eq!(Ok(3), run_a(&parse(input).unwrap()));
+    }
+
+    #[test]
+    fn test_blueprint_errors() {
+        assert_eq!(
+            Err(Error::parse(25, 2, 36, "expected a number of steps")
+                .with_source("Perform a diagnostic checksum after")),
+            parse("Begin in state A.\nPerform a diagnostic checksum after")
+        );
+    }
+}
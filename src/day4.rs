@@ -0,0 +1,76 @@
+use error::Error;
+use parser;
+use solution::Solution;
+use std::collections::HashSet;
+
+#[derive(Debug, Default)]
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Vec<String>>;
+    type A = usize;
+    type B = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<Vec<String>>, Error> {
+        Ok(parse(input))
+    }
+
+    fn part_a(&self, passphrases: &Vec<Vec<String>>) -> Result<usize, Error> {
+        Ok(run_a(passphrases))
+    }
+
+    fn part_b(&self, passphrases: &Vec<Vec<String>>) -> Result<usize, Error> {
+        Ok(run_b(passphrases))
+    }
+}
+
+/// Words of each passphrase. Any word makes a passphrase, valid or not, so there is nothing
+/// to report.
+pub fn parse(input: &str) -> Vec<Vec<String>> {
+    parser::lines(4, input)
+        .map(|line| line.words().map(String::from).collect())
+        .collect()
+}
+
+pub fn run_a(passphrases: &[Vec<String>]) -> usize {
+    let logic = |words: &Vec<String>| {
+        let mut unique = HashSet::new();
+        words.iter().all(|word| unique.insert(word))
+    };
+
+    passphrases.iter().filter(|words| logic(words)).count()
+}
+
+pub fn run_b(passphrases: &[Vec<String>]) -> usize {
+    let logic = |words: &Vec<String>| {
+        let mut unique = HashSet::new();
+        words.iter().all(|word| {
+            let mut chars: Vec<char> = word.chars().collect();
+            chars.sort();
+            unique.insert(chars)
+        })
+    };
+
+    passphrases.iter().filter(|words| logic(words)).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_a() {
+        assert_eq!(1, run_a(&parse("aa bb cc dd ee")));
+        assert_eq!(0, run_a(&parse("aa bb cc dd aa")));
+        assert_eq!(1, run_a(&parse("aa bb cc dd aaa")));
+    }
+
+    #[test]
+    fn test_run_b() {
+        assert_eq!(1, run_b(&parse("abcde fghij")));
+        assert_eq!(0, run_b(&parse("abcde xyz ecdab")));
+        assert_eq!(1, run_b(&parse("a ab abc abd abf abj")));
+        assert_eq!(1, run_b(&parse("iiii oiii ooii oooi oooo")));
+        assert_eq!(0, run_b(&parse("oiii ioii iioi iiio")));
+    }
+}
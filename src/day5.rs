@@ -0,0 +1,101 @@
+use error::Error;
+use parser;
+use solution::Solution;
+
+#[derive(Debug, Default)]
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<isize>;
+    type A = usize;
+    type B = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<isize>, Error> {
+        parse(input)
+    }
+
+    fn part_a(&self, offsets: &Vec<isize>) -> Result<usize, Error> {
+        Ok(run_a(offsets))
+    }
+
+    fn part_b(&self, offsets: &Vec<isize>) -> Result<usize, Error> {
+        Ok(run_b(offsets))
+    }
+}
+
+/// Jump offsets, one per line.
+pub fn parse(input: &str) -> Result<Vec<isize>, Error> {
+    parser::lines(5, input)
+        .map(|line| (line, line.text.trim()))
+        .filter(|&(_, offset)| !offset.is_empty())
+        .map(|(line, offset)| line.parse(offset, "an offset"))
+        .collect()
+}
+
+pub fn run_a(offsets: &[isize]) -> usize {
+    let logic = |_| 1;
+    jumper(offsets, logic)
+}
+
+pub fn run_b(offsets: &[isize]) -> usize {
+    let logic = |offset| {
+        if offset < 3 {
+            1
+        } else {
+            -1
+        }
+    };
+    jumper(offsets, logic)
+}
+
+fn jumper(offsets: &[isize], logic: fn(isize) -> isize) -> usize {
+    let mut input = offsets.to_vec();
+    let mut position = 0isize;
+    let mut count = 0;
+
+    while let Some(offset) = input.get_mut(position as usize) {
+        let increment = logic(*offset);
+        *offset += increment;
+        position += *offset - increment;
+        count += 1;
+    }
+
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_a() {
+        let input = "
+0
+3
+0
+1
+-3
+";
+        assert_eq!(5, run_a(&parse(input).unwrap()));
+    }
+
+    #[test]
+    fn test_run_b() {
+        let input = "
+0
+3
+0
+1
+-3
+";
+        assert_eq!(10, run_b(&parse(input).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Err(Error::parse(5, 2, 2, "expected an offset, found \"+-3\"").with_source(" +-3")),
+            parse("0\n +-3")
+        );
+    }
+}
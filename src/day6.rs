@@ -0,0 +1,112 @@
+use error::Error;
+use parser;
+use solution::Solution;
+use std::collections::HashMap;
+
+#[derive(Debug, Default)]
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<usize>;
+    type A = usize;
+    type B = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<usize>, Error> {
+        parse(input)
+    }
+
+    fn part_a(&self, banks: &Vec<usize>) -> Result<usize, Error> {
+        Ok(run_a(banks))
+    }
+
+    fn part_b(&self, banks: &Vec<usize>) -> Result<usize, Error> {
+        Ok(run_b(banks))
+    }
+}
+
+/// Blocks of each memory bank, of which there is at least one.
+pub fn parse(input: &str) -> Result<Vec<usize>, Error> {
+    let line = parser::single_line(6, input)?;
+    let banks = line.words()
+        .map(|n| line.parse(n, "a number of blocks"))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if banks.is_empty() {
+        return Err(line.error_at_end("expected at least one bank"));
+    }
+
+    Ok(banks)
+}
+
+fn checksum(banks: &[usize]) -> String {
+    banks.iter().map(|n| n.to_string() + ",").collect()
+}
+
+fn most_blocks(banks: &[usize]) -> (usize, usize) {
+    let (index, &value) = banks
+        .iter()
+        .rev()
+        .enumerate()
+        .max_by_key(|&(_, v)| v)
+        .unwrap();
+    (banks.len() - index - 1, value)
+}
+
+fn redistribute(banks: &mut [usize], (index, value): (usize, usize)) {
+    let range = (0..banks.len()).cycle().skip(index + 1).take(value);
+
+    banks[index] = 0;
+    range.for_each(|index| banks[index] += 1);
+}
+
+pub fn run_a(banks: &[usize]) -> usize {
+    let logic = |_, count| count;
+    looper(banks, logic)
+}
+
+pub fn run_b(banks: &[usize]) -> usize {
+    let logic = |previous_count, count| count - previous_count;
+    looper(banks, logic)
+}
+
+fn looper(banks: &[usize], logic: fn(usize, usize) -> usize) -> usize {
+    let mut input = banks.to_vec();
+    let mut checksums = HashMap::new();
+    let mut count = 0;
+
+    while !checksums.contains_key(&checksum(&input)) {
+        checksums.insert(checksum(&input), count);
+        count += 1;
+        let bank = most_blocks(&input);
+        redistribute(&mut input, bank);
+    }
+
+    logic(checksums[&checksum(&input)], count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_a() {
+        let input = "0	2	7	0";
+        assert_eq!(5, run_a(&parse(input).unwrap()));
+    }
+
+    #[test]
+    fn test_run_b() {
+        let input = "0	2	7	0";
+        assert_eq!(4, run_b(&parse(input).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Err(Error::parse(6, 1, 3, "expected a number of blocks, found \"x\"")
+                .with_source("0 x")),
+            parse("0 x")
+        );
+        assert!(parse("").is_err());
+    }
+}
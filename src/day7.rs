@@ -0,0 +1,207 @@
+use error::Error;
+use parser;
+use regex::Regex;
+use solution::Solution;
+use std::collections::HashMap;
+
+#[derive(Debug, Default)]
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Towers;
+    type A = String;
+    type B = u32;
+
+    fn parse(&self, input: &str) -> Result<Towers, Error> {
+        parse(input)
+    }
+
+    fn part_a(&self, input: &Towers) -> Result<String, Error> {
+        run_a(input)
+            .map(String::from)
+            .ok_or_else(|| Error::runtime(7, "Cannot find the bottom program"))
+    }
+
+    fn part_b(&self, input: &Towers) -> Result<u32, Error> {
+        run_b(input).ok_or_else(|| Error::runtime(7, "Cannot find an unbalanced program"))
+    }
+}
+
+pub fn run_a(tree: &Towers) -> Option<&str> {
+    root(tree)
+}
+
+pub fn run_b(tree: &Towers) -> Option<u32> {
+    if let Some(root) = root(tree) {
+        populate_all_energy(tree, root)
+    } else {
+        None
+    }
+}
+
+fn root(tree: &Towers) -> Option<&str> {
+    tree.iter()
+        .find(|&(_, value)| value.parent.is_empty())
+        .map(|(name, _)| name.as_str())
+}
+
+macro_rules! distance {
+    ($x:expr, $y:expr) => (
+        if $x < $y {
+            $y - $x
+        } else {
+            $x - $y
+        })
+}
+
+fn populate_all_energy<'a>(tree: &Towers, root: &'a str) -> Option<u32> {
+    let mut accumulated_energy = HashMap::new();
+    let mut stack: Vec<&str> = vec![root];
+
+    while !stack.is_empty() {
+        let &name = stack.last().unwrap();
+        let tower = tree.get(name).unwrap();
+
+        if tower.sub_towers.is_empty() {
+            accumulated_energy.insert(name, tower.energy);
+            stack.pop();
+        } else {
+            let children = tower
+                .sub_towers
+                .iter()
+                .map(|t| *accumulated_energy.entry(t).or_insert(0))
+                .collect::<Vec<u32>>();
+            let unbalanced = children.iter().find(|e| *e != &children[0]);
+            let children_energy = children.iter().sum::<u32>();
+
+            if unbalanced.is_none() && children_energy != 0 {
+                accumulated_energy.insert(name, tower.energy + children_energy);
+                stack.pop();
+            } else if unbalanced.is_none() && children_energy == 0 {
+                tower.sub_towers.iter().for_each(|t| stack.push(t));
+            } else {
+                return Some(distance!(*unbalanced.unwrap(), children[0]));
+            }
+        }
+    }
+
+    None
+}
+
+type SubTower = Vec<String>;
+
+#[derive(Debug, Hash, Eq, PartialEq)]
+pub struct Tower {
+    parent: String,
+    energy: u32,
+    sub_towers: SubTower,
+}
+
+pub type Towers = HashMap<String, Tower>;
+
+pub fn parse(input: &str) -> Result<Towers, Error> {
+    let mut towers: HashMap<String, Tower> = HashMap::new();
+    for line in parser::lines(7, input) {
+        let mut it = line.split("->");
+
+        let tower = it.token("a program")?;
+        let (name, energy) = parse_tower(tower)
+            .ok_or_else(|| line.error(tower, "expected `name (weight)`"))?;
+
+        let sub_towers = it.next().map_or(vec![], |sub_tower| {
+            sub_tower.split(',').map(|t| t.trim().to_owned()).collect()
+        });
+        it.end()?;
+
+        towers
+            .entry(name.clone())
+            .or_insert(Tower {
+                parent: String::new(),
+                energy,
+                sub_towers: sub_towers.clone(),
+            })
+            .energy = energy;
+
+        towers.get_mut(&name).unwrap().sub_towers = sub_towers.clone();
+
+        sub_towers.iter().for_each(|sub_tower| {
+            towers
+                .entry(sub_tower.clone())
+                .or_insert(Tower {
+                    parent: name.clone(),
+                    energy: 0,
+                    sub_towers: vec![],
+                })
+                .parent = name.clone()
+        });
+    }
+
+    Ok(towers)
+}
+
+fn parse_tower(tower: &str) -> Option<(String, u32)> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^(\w+) \((\d+)\)$").unwrap();
+    }
+    let captures = RE.captures(tower)?;
+    Some((captures[1].to_owned(), captures[2].parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_a() {
+        let input = "pbga (66)
+xhth (57)
+ebii (61)
+havc (66)
+ktlj (57)
+fwft (72) -> ktlj, cntj, xhth
+qoyq (66)
+padx (45) -> pbga, havc, qoyq
+tknk (41) -> ugml, padx, fwft
+jptl (61)
+ugml (68) -> gyxo, ebii, jptl
+gyxo (61)
+cntj (57)";
+
+        if let Ok(towers) = parse(&input) {
+            assert_eq!(Some("tknk"), run_a(&towers));
+        } else {
+            assert!(false);
+        }
+    }
+
+    #[test]
+    fn test_run_b() {
+        let input = "pbga (66)
+xhth (57)
+ebii (61)
+havc (66)
+ktlj (57)
+fwft (72) -> ktlj, cntj, xhth
+qoyq (66)
+padx (45) -> pbga, havc, qoyq
+tknk (41) -> ugml, padx, fwft
+jptl (61)
+ugml (68) -> gyxo, ebii, jptl
+gyxo (61)
+cntj (57)";
+
+        if let Ok(towers) = parse(&input) {
+            assert_eq!(Some(8), run_b(&towers));
+        } else {
+            assert!(false);
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Err(Error::parse(7, 2, 1, "expected `name (weight)`").with_source("xhth 57")),
+            parse("pbga (66)\nxhth 57")
+        );
+    }
+}
@@ -0,0 +1,195 @@
+use error::Error;
+use parser;
+use solution::Solution;
+use std::collections::HashMap;
+
+#[derive(Debug, Default)]
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Vec<Instruction>;
+    type A = i32;
+    type B = i32;
+
+    fn parse(&self, input: &str) -> Result<Vec<Instruction>, Error> {
+        parse(input)
+    }
+
+    fn part_a(&self, program: &Vec<Instruction>) -> Result<i32, Error> {
+        let (heap, _) = interpreter(program);
+        run_a(&heap).ok_or_else(|| Error::runtime(8, "No register was written"))
+    }
+
+    fn part_b(&self, program: &Vec<Instruction>) -> Result<i32, Error> {
+        let (_, history) = interpreter(program);
+        run_b(&history).ok_or_else(|| Error::runtime(8, "No instruction was executed"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Inc,
+    Dec,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparaison {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+/// `<register> <operator> <offset> if <lhs_if> <op_if> <rhs_if>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    pub register: String,
+    pub op: Operator,
+    pub offset: i32,
+    pub lhs_if: String,
+    pub op_if: Comparaison,
+    pub rhs_if: i32,
+}
+
+pub fn run_a(heap: &HashMap<&str, i32>) -> Option<i32> {
+    heap.values().max().cloned()
+}
+
+pub fn run_b(history: &[i32]) -> Option<i32> {
+    history.iter().max().cloned()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Instruction>, Error> {
+    parser::lines(8, input)
+        .map(|line| {
+            let mut tokens = line.words();
+
+            let register = tokens.token("a register")?;
+            let op = tokens.token("an operator")?;
+            let offset = tokens.parse("an offset")?;
+            tokens.literal("if")?;
+            let lhs_if = tokens.token("a register")?;
+            let op_if = tokens.token("a comparaison operator")?;
+            let rhs_if = tokens.parse("a value")?;
+            tokens.end()?;
+
+            Ok(Instruction {
+                register: register.to_owned(),
+                op: operator(op)
+                    .ok_or_else(|| line.error(op, format!("Not recognized operator: {}", op)))?,
+                offset,
+                lhs_if: lhs_if.to_owned(),
+                op_if: comparaison(op_if).ok_or_else(|| {
+                    line.error(op_if, format!("Do not recognize comparaison operator: {}", op_if))
+                })?,
+                rhs_if,
+            })
+        })
+        .collect()
+}
+
+/// Runs `program`, returning the final registers and the value of the written register after
+/// each instruction.
+pub fn interpreter(program: &[Instruction]) -> (HashMap<&str, i32>, Vec<i32>) {
+    let mut heap = HashMap::new();
+    let mut history = Vec::new();
+
+    for instruction in program {
+        let lhs_if = *heap.get(instruction.lhs_if.as_str()).unwrap_or(&0);
+        let condition = compare(instruction.op_if, lhs_if, instruction.rhs_if);
+
+        let register = heap.entry(instruction.register.as_str()).or_insert(0);
+        if condition {
+            *register = update(instruction.op, *register, instruction.offset);
+        }
+
+        history.push(*register);
+    }
+
+    (heap, history)
+}
+
+fn operator(op: &str) -> Option<Operator> {
+    match op {
+        "inc" => Some(Operator::Inc),
+        "dec" => Some(Operator::Dec),
+        _ => None,
+    }
+}
+
+fn comparaison(op: &str) -> Option<Comparaison> {
+    match op {
+        "<" => Some(Comparaison::Lt),
+        "<=" => Some(Comparaison::Le),
+        ">" => Some(Comparaison::Gt),
+        ">=" => Some(Comparaison::Ge),
+        "==" => Some(Comparaison::Eq),
+        "!=" => Some(Comparaison::Ne),
+        _ => None,
+    }
+}
+
+fn compare(op: Comparaison, lhs: i32, rhs: i32) -> bool {
+    match op {
+        Comparaison::Lt => lhs < rhs,
+        Comparaison::Le => lhs <= rhs,
+        Comparaison::Gt => lhs > rhs,
+        Comparaison::Ge => lhs >= rhs,
+        Comparaison::Eq => lhs == rhs,
+        Comparaison::Ne => lhs != rhs,
+    }
+}
+
+fn update(op: Operator, register: i32, offset: i32) -> i32 {
+    match op {
+        Operator::Dec => register - offset,
+        Operator::Inc => register + offset,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_a() {
+        let input = "b inc 5 if a > 1
+a inc 1 if b < 5
+c dec -10 if a >= 1
+c inc -20 if c == 10
+";
+        let program = parse(input).unwrap();
+        let (heap, _) = interpreter(&program);
+        assert_eq!(Some(1), run_a(&heap));
+    }
+
+    #[test]
+    fn test_run_b() {
+        let input = "b inc 5 if a > 1
+a inc 1 if b < 5
+c dec -10 if a >= 1
+c inc -20 if c == 10
+";
+        let program = parse(input).unwrap();
+        let (_, history) = interpreter(&program);
+        assert_eq!(Some(10), run_b(&history));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err(Error::parse(8, 2, 14, "Do not recognize comparaison operator: =<")
+                .with_source("a inc 1 if b =< 5")),
+            parse("b inc 5 if a > 1\na inc 1 if b =< 5")
+        );
+        assert_eq!(
+            Err(Error::parse(8, 1, 7, "expected an offset, found \"five\"")
+                .with_source("b inc five if a > 1")),
+            parse("b inc five if a > 1")
+        );
+        assert!(parse("b inc 5").is_err());
+        assert!(parse("b add 5 if a > 1").is_err());
+    }
+}
@@ -0,0 +1,120 @@
+use error::Error;
+use parser;
+use solution::Solution;
+
+#[derive(Debug, Default)]
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<char>;
+    type A = u32;
+    type B = u32;
+
+    fn parse(&self, input: &str) -> Result<Vec<char>, Error> {
+        parse(input)
+    }
+
+    fn part_a(&self, stream: &Vec<char>) -> Result<u32, Error> {
+        Ok(run_a(stream))
+    }
+
+    fn part_b(&self, stream: &Vec<char>) -> Result<u32, Error> {
+        Ok(run_b(stream))
+    }
+}
+
+/// Characters of the stream, without those cancelled by `!`.
+pub fn parse(input: &str) -> Result<Vec<char>, Error> {
+    let mut it = parser::single_line(9, input)?.text.chars();
+    let mut stream = Vec::new();
+
+    while let Some(character) = it.next() {
+        match character {
+            '!' => {
+                it.next();
+            }
+            _ => stream.push(character),
+        };
+    }
+
+    Ok(stream)
+}
+
+pub fn run_a(stream: &[char]) -> u32 {
+    let mut score = 0;
+    let mut depth = 0;
+    let mut discarding = false;
+
+    let mut logic = |character: &char| {
+        match *character {
+            '<' if !discarding => discarding = true,
+            '>' => discarding = false,
+            '{' if !discarding => depth += 1,
+            '}' if !discarding => {
+                score += depth;
+                depth -= 1;
+            }
+            _ => (),
+        };
+        score
+    };
+
+    streamer(stream, &mut logic)
+}
+
+pub fn run_b(stream: &[char]) -> u32 {
+    let mut score = 0;
+    let mut discarding = false;
+
+    let mut logic = |character: &char| {
+        match *character {
+            '<' if !discarding => discarding = true,
+            '>' => discarding = false,
+            _ if discarding => score += 1,
+            _ => (),
+        };
+        score
+    };
+
+    streamer(stream, &mut logic)
+}
+
+fn streamer(stream: &[char], logic: &mut FnMut(&char) -> u32) -> u32 {
+    stream.iter().fold(0, |_, character| logic(character))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_a() {
+        assert_eq!(1, run_a(&parse("{}").unwrap()));
+        assert_eq!(6, run_a(&parse("{{{}}}").unwrap()));
+        assert_eq!(5, run_a(&parse("{{},{}}").unwrap()));
+        assert_eq!(16, run_a(&parse("{{{},{},{{}}}}").unwrap()));
+        assert_eq!(1, run_a(&parse("{<a>,<a>,<a>,<a>}").unwrap()));
+        assert_eq!(9, run_a(&parse("{{<ab>},{<ab>},{<ab>},{<ab>}}").unwrap()));
+        assert_eq!(9, run_a(&parse("{{<!!>},{<!!>},{<!!>},{<!!>}}").unwrap()));
+        assert_eq!(3, run_a(&parse("{{<a!>},{<a!>},{<a!>},{<ab>}}").unwrap()));
+        assert_eq!(0, run_a(&parse("<>").unwrap()));
+        assert_eq!(0, run_a(&parse("<random characters>").unwrap()));
+        assert_eq!(0, run_a(&parse("<<<<>").unwrap()));
+        assert_eq!(0, run_a(&parse("<{!>}>").unwrap()));
+        assert_eq!(0, run_a(&parse("<!!>").unwrap()));
+        assert_eq!(0, run_a(&parse("<!!!>>").unwrap()));
+        assert_eq!(0, run_a(&parse("<{o\"i!a,<{i<a>").unwrap()));
+    }
+
+    #[test]
+    fn test_run_b() {
+        assert_eq!(0, run_b(&parse("<>").unwrap()));
+        assert_eq!(17, run_b(&parse("<random characters>").unwrap()));
+        assert_eq!(3, run_b(&parse("<<<<>").unwrap()));
+        assert_eq!(2, run_b(&parse("<{!>}>").unwrap()));
+        assert_eq!(0, run_b(&parse("<!!>").unwrap()));
+        assert_eq!(0, run_b(&parse("<!!!>>").unwrap()));
+        assert_eq!(10, run_b(&parse("<{o\"i!a,<{i<a>").unwrap()));
+        assert!(parse("{}\n{}").is_err());
+    }
+}
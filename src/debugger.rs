@@ -0,0 +1,707 @@
+//! Interactive debugger of the assembly of days 18 and 23, stepping programs until they hit a
+//! breakpoint, have a watched register changed, or cannot go on.
+
+#[cfg(feature = "day18")]
+use day18::{self, Duo, Sound};
+#[cfg(feature = "day23")]
+use day23::Coprocessor;
+use error;
+use failure::Error;
+use registry::Part;
+use std::fmt;
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+use vm::{self, register_name, Cpu, Instruction, InstructionSet, Register, Registers, State};
+
+const HELP: &str = "Commands:
+    s, step [<n>]                  runs n instructions, 1 by default
+    c, continue                    runs until a breakpoint or the end
+    b, break <index>               stops before the instruction at index
+    b, break <reg> <cmp> <value>   stops when the condition becomes true, cmp being one of
+                                   == != < <= > >=
+    w, watch <reg>                 stops when the register changes
+    d, delete <n>                  deletes the breakpoint or watchpoint numbered n
+    i, info                        lists the breakpoints and watchpoints
+    r, registers                   dumps the registers and queues of each program
+    l, list                        lists the program, marking where each one is
+    h, help                        prints this help
+    q, quit                        stops debugging";
+
+/// A program, or copies of it running side by side, which the debugger can step.
+pub trait Debuggee {
+    fn program(&self) -> &[Instruction];
+
+    /// Number of programs running side by side.
+    fn programs(&self) -> usize;
+
+    fn ip(&self, program: usize) -> usize;
+
+    fn state(&self, program: usize) -> State;
+
+    fn registers(&self, program: usize) -> &Registers;
+
+    /// What the dialect of `program` keeps besides its registers, such as its queues.
+    fn dump(&self, program: usize) -> String;
+
+    /// Runs an instruction of every program which can, returning false once none can or they
+    /// are finished.
+    fn step(&mut self) -> Result<bool, error::Error>;
+}
+
+impl<'a, I: InstructionSet + fmt::Debug> Debuggee for Cpu<'a, I> {
+    fn program(&self) -> &[Instruction] {
+        Cpu::program(self)
+    }
+
+    fn programs(&self) -> usize {
+        1
+    }
+
+    fn ip(&self, _program: usize) -> usize {
+        self.ip
+    }
+
+    fn state(&self, _program: usize) -> State {
+        self.state
+    }
+
+    fn registers(&self, _program: usize) -> &Registers {
+        &self.registers
+    }
+
+    fn dump(&self, _program: usize) -> String {
+        format!("{:?}", self.isa)
+    }
+
+    fn step(&mut self) -> Result<bool, error::Error> {
+        Ok(Cpu::step(self)? == State::Running && !self.isa.finished())
+    }
+}
+
+#[cfg(feature = "day18")]
+impl<'a> Debuggee for Duo<'a> {
+    fn program(&self) -> &[Instruction] {
+        self.cpus[0].program()
+    }
+
+    fn programs(&self) -> usize {
+        self.cpus.len()
+    }
+
+    fn ip(&self, program: usize) -> usize {
+        self.cpus[program].ip
+    }
+
+    fn state(&self, program: usize) -> State {
+        self.cpus[program].state
+    }
+
+    fn registers(&self, program: usize) -> &Registers {
+        &self.cpus[program].registers
+    }
+
+    fn dump(&self, program: usize) -> String {
+        format!("{:?}", self.cpus[program].isa)
+    }
+
+    fn step(&mut self) -> Result<bool, error::Error> {
+        Duo::step(self)
+    }
+}
+
+/// Where a program was before a step, and its registers.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    pub ip: usize,
+    pub state: State,
+    pub registers: Registers,
+}
+
+/// Keeps where each program of `target` is in `snapshots`, reusing the registers they hold
+/// from the previous step.
+pub fn snapshot<D: Debuggee>(target: &D, snapshots: &mut Vec<Snapshot>) {
+    snapshots.truncate(target.programs());
+
+    for program in 0..target.programs() {
+        let (ip, state, registers) = (
+            target.ip(program),
+            target.state(program),
+            target.registers(program),
+        );
+
+        if let Some(snapshot) = snapshots.get_mut(program) {
+            snapshot.ip = ip;
+            snapshot.state = state;
+            snapshot.registers.clone_from(registers);
+            continue;
+        }
+
+        snapshots.push(Snapshot {
+            ip,
+            state,
+            registers: registers.clone(),
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Comparison {
+    pub fn holds(&self, a: i64, b: i64) -> bool {
+        match *self {
+            Comparison::Eq => a == b,
+            Comparison::Ne => a != b,
+            Comparison::Lt => a < b,
+            Comparison::Le => a <= b,
+            Comparison::Gt => a > b,
+            Comparison::Ge => a >= b,
+        }
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(match *self {
+            Comparison::Eq => "==",
+            Comparison::Ne => "!=",
+            Comparison::Lt => "<",
+            Comparison::Le => "<=",
+            Comparison::Gt => ">",
+            Comparison::Ge => ">=",
+        })
+    }
+}
+
+impl FromStr for Comparison {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Comparison, Error> {
+        match s {
+            "==" => Ok(Comparison::Eq),
+            "!=" => Ok(Comparison::Ne),
+            "<" => Ok(Comparison::Lt),
+            "<=" => Ok(Comparison::Le),
+            ">" => Ok(Comparison::Gt),
+            ">=" => Ok(Comparison::Ge),
+            _ => Err(format_err!("Unknown comparison: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Breakpoint {
+    /// Stops before the instruction at this index runs.
+    At(usize),
+    /// Stops when the comparison of a register with a value becomes true.
+    When(Register, Comparison, i64),
+    /// A watchpoint, stopping when the register changes.
+    Watch(Register),
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Breakpoint::At(index) => write!(f, "break at {}", index),
+            Breakpoint::When(r, comparison, value) => {
+                write!(f, "break when {} {} {}", register_name(r), comparison, value)
+            }
+            Breakpoint::Watch(r) => write!(f, "watch {}", register_name(r)),
+        }
+    }
+}
+
+/// Why the debugger stopped running the programs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Stop {
+    /// The number of steps asked for ran.
+    Stepped,
+    /// `program` hit the breakpoint numbered `number`, from 1.
+    Breakpoint { program: usize, number: usize },
+    Watchpoint {
+        program: usize,
+        register: Register,
+        old: i64,
+        new: i64,
+    },
+    /// Every program halted, waits for a value which will never come, or is finished.
+    Ended,
+}
+
+impl fmt::Display for Stop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Stop::Stepped => Ok(()),
+            Stop::Breakpoint { program, number } => {
+                write!(f, "#{} hit breakpoint {}", program, number)
+            }
+            Stop::Watchpoint {
+                program,
+                register,
+                old,
+                new,
+            } => write!(
+                f,
+                "#{} changed {}: {} -> {}",
+                program,
+                register_name(register),
+                old,
+                new
+            ),
+            Stop::Ended => write!(f, "every program halted, is deadlocked or is finished"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Step(usize),
+    Continue,
+    Break(Breakpoint),
+    /// Deletes the breakpoint numbered from 1.
+    Delete(usize),
+    Info,
+    Registers,
+    List,
+    Help,
+    Quit,
+}
+
+impl FromStr for Command {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Command, Error> {
+        let words: Vec<_> = s.split_whitespace().collect();
+        let (command, args) = match words.split_first() {
+            Some((command, args)) => (*command, args),
+            None => ("step", &[][..]),
+        };
+
+        match (command, args) {
+            ("s", &[]) | ("step", &[]) => Ok(Command::Step(1)),
+            ("s", &[n]) | ("step", &[n]) => Ok(Command::Step(number(n)?)),
+            ("c", &[]) | ("continue", &[]) => Ok(Command::Continue),
+            ("b", &[index]) | ("break", &[index]) => {
+                Ok(Command::Break(Breakpoint::At(number(index)?)))
+            }
+            ("b", &[r, comparison, value]) | ("break", &[r, comparison, value]) => {
+                let value = value
+                    .parse()
+                    .map_err(|_| format_err!("Invalid value: {}", value))?;
+                Ok(Command::Break(Breakpoint::When(
+                    register(r)?,
+                    comparison.parse()?,
+                    value,
+                )))
+            }
+            ("w", &[r]) | ("watch", &[r]) => Ok(Command::Break(Breakpoint::Watch(register(r)?))),
+            ("d", &[n]) | ("delete", &[n]) => Ok(Command::Delete(number(n)?)),
+            ("i", &[]) | ("info", &[]) => Ok(Command::Info),
+            ("r", &[]) | ("registers", &[]) => Ok(Command::Registers),
+            ("l", &[]) | ("list", &[]) => Ok(Command::List),
+            ("h", &[]) | ("help", &[]) => Ok(Command::Help),
+            ("q", &[]) | ("quit", &[]) => Ok(Command::Quit),
+            _ => Err(format_err!("Unknown command: {} (try help)", s.trim())),
+        }
+    }
+}
+
+fn number(s: &str) -> Result<usize, Error> {
+    s.parse().map_err(|_| format_err!("Invalid number: {}", s))
+}
+
+fn register(s: &str) -> Result<Register, Error> {
+    match s.as_bytes() {
+        &[name] if name >= b'a' && name <= b'z' => Ok(vm::register(char::from(name))),
+        _ => Err(format_err!("Invalid register: {}", s)),
+    }
+}
+
+/// Breakpoints and watchpoints, checked after each step.
+#[derive(Debug, Clone, Default)]
+pub struct Debugger {
+    pub breakpoints: Vec<Breakpoint>,
+}
+
+impl Debugger {
+    pub fn new() -> Debugger {
+        Debugger::default()
+    }
+
+    /// Steps `target` until it hits a breakpoint, cannot go on, or `steps` ran if given.
+    pub fn run<D: Debuggee>(&self, target: &mut D, steps: Option<usize>) -> Result<Stop, Error> {
+        let mut taken = 0;
+        let mut before = Vec::new();
+
+        loop {
+            if steps.map_or(false, |steps| taken >= steps) {
+                return Ok(Stop::Stepped);
+            }
+
+            snapshot(target, &mut before);
+            let running = target.step()?;
+            taken += 1;
+
+            if let Some(stop) = self.check(target, &before) {
+                return Ok(stop);
+            }
+            if !running {
+                return Ok(Stop::Ended);
+            }
+        }
+    }
+
+    /// The first breakpoint hit by a step from where the programs were `before`, with their
+    /// registers.
+    fn check<D: Debuggee>(&self, target: &D, before: &[Snapshot]) -> Option<Stop> {
+        for (program, &Snapshot { ip, ref registers, .. }) in before.iter().enumerate() {
+            let (old, new) = (registers, target.registers(program));
+            // A program waiting at an instruction did not move onto it again, while one jumping
+            // to where it was did
+            let moved = target.ip(program) != ip || target.state(program) == State::Running;
+
+            for (i, breakpoint) in self.breakpoints.iter().enumerate() {
+                let hit = match *breakpoint {
+                    Breakpoint::At(index) => {
+                        moved && target.state(program) != State::Halted
+                            && target.ip(program) == index
+                    }
+                    Breakpoint::When(r, comparison, value) => {
+                        comparison.holds(new.get(r), value) && !comparison.holds(old.get(r), value)
+                    }
+                    Breakpoint::Watch(r) if old.get(r) != new.get(r) => {
+                        return Some(Stop::Watchpoint {
+                            program,
+                            register: r,
+                            old: old.get(r),
+                            new: new.get(r),
+                        })
+                    }
+                    Breakpoint::Watch(_) => false,
+                };
+
+                if hit {
+                    return Some(Stop::Breakpoint {
+                        program,
+                        number: i + 1,
+                    });
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Reads commands from `input` until `quit` or its end, writing what they show to `output`.
+    /// An empty line steps once.
+    pub fn repl<D, R, W>(&mut self, target: &mut D, input: R, output: &mut W) -> Result<(), Error>
+    where
+        D: Debuggee,
+        R: BufRead,
+        W: Write,
+    {
+        let registers = target.registers(0).values().len();
+        locate(target, output)?;
+
+        for line in input.lines() {
+            let command = match line?.parse::<Command>() {
+                Ok(Command::Break(breakpoint)) if !has_registers(breakpoint, registers) => {
+                    Err(format_err!("The programs only have {} registers", registers))
+                }
+                command => command,
+            };
+
+            match command {
+                Ok(Command::Step(steps)) => {
+                    stopped(&self.run(target, Some(steps))?, output)?;
+                    locate(target, output)?;
+                }
+                Ok(Command::Continue) => {
+                    stopped(&self.run(target, None)?, output)?;
+                    locate(target, output)?;
+                }
+                Ok(Command::Break(breakpoint)) => {
+                    self.breakpoints.push(breakpoint);
+                    writeln!(output, "{}: {}", self.breakpoints.len(), breakpoint)?;
+                }
+                Ok(Command::Delete(n)) if n >= 1 && n <= self.breakpoints.len() => {
+                    writeln!(output, "deleted {}", self.breakpoints.remove(n - 1))?;
+                }
+                Ok(Command::Delete(n)) => writeln!(output, "No breakpoint {}", n)?,
+                Ok(Command::Info) => for (i, breakpoint) in self.breakpoints.iter().enumerate() {
+                    writeln!(output, "{}: {}", i + 1, breakpoint)?;
+                },
+                Ok(Command::Registers) => for program in 0..target.programs() {
+                    let values = target.registers(program).values();
+                    let registers: Vec<_> = values
+                        .iter()
+                        .enumerate()
+                        .map(|(r, value)| format!("{}={}", register_name(r as Register), value))
+                        .collect();
+
+                    writeln!(output, "#{} {}", program, registers.join(" "))?;
+                    writeln!(output, "#{} {}", program, target.dump(program))?;
+                },
+                Ok(Command::List) => list(self, target, output)?,
+                Ok(Command::Help) => writeln!(output, "{}", HELP)?,
+                Ok(Command::Quit) => break,
+                Err(err) => writeln!(output, "error: {}", err)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn has_registers(breakpoint: Breakpoint, registers: usize) -> bool {
+    match breakpoint {
+        Breakpoint::At(_) => true,
+        Breakpoint::When(r, _, _) | Breakpoint::Watch(r) => usize::from(r) < registers,
+    }
+}
+
+fn stopped<W: Write>(stop: &Stop, output: &mut W) -> Result<(), Error> {
+    if *stop != Stop::Stepped {
+        writeln!(output, "{}", stop)?;
+    }
+
+    Ok(())
+}
+
+/// Writes where each program is.
+fn locate<D: Debuggee, W: Write>(target: &D, output: &mut W) -> Result<(), Error> {
+    for program in 0..target.programs() {
+        match target.state(program) {
+            State::Halted => writeln!(output, "#{} halted", program)?,
+            state => writeln!(
+                output,
+                "#{} {:>4}  {}{}",
+                program,
+                target.ip(program),
+                target.program()[target.ip(program)],
+                if state == State::Waiting {
+                    "  (waiting)"
+                } else {
+                    ""
+                }
+            )?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes the whole program, marking the breakpoints with `*` and where each program is.
+fn list<D, W>(debugger: &Debugger, target: &D, output: &mut W) -> Result<(), Error>
+where
+    D: Debuggee,
+    W: Write,
+{
+    for (index, instruction) in target.program().iter().enumerate() {
+        let mark = if debugger.breakpoints.contains(&Breakpoint::At(index)) {
+            "*"
+        } else {
+            " "
+        };
+        let here: Vec<_> = (0..target.programs())
+            .filter(|&program| {
+                target.state(program) != State::Halted && target.ip(program) == index
+            })
+            .map(|program| format!("#{}", program))
+            .collect();
+
+        let instruction = instruction.to_string();
+        let line = format!("{}{:>4}  {:<16}{}", mark, index, instruction, here.join(" "));
+        writeln!(output, "{}", line.trim_right())?;
+    }
+
+    Ok(())
+}
+
+/// Something done with the programs of a day, whichever `Debuggee` runs them.
+pub trait Session {
+    type Output;
+
+    fn run<D: Debuggee>(self, target: &mut D) -> Result<Self::Output, Error>;
+}
+
+/// Runs `session` on `program`, the input of `day`, as run by `part`.
+#[cfg_attr(not(any(feature = "day18", feature = "day23")), allow(unused_variables))]
+pub fn start<S>(day: u8, part: Part, program: &str, session: S) -> Result<S::Output, Error>
+where
+    S: Session,
+{
+    match (day, part) {
+        #[cfg(feature = "day18")]
+        (18, Part::A) => {
+            let program = day18::parse(program)?;
+            session.run(&mut Cpu::new(&program, Sound::default()))
+        }
+        #[cfg(feature = "day18")]
+        (18, Part::B) => {
+            let program = day18::parse(program)?;
+            session.run(&mut Duo::new(&program))
+        }
+        #[cfg(feature = "day23")]
+        (23, part) => {
+            let program = vm::assemble::<Coprocessor>(program)?;
+            let mut cpu = Cpu::new(&program, Coprocessor::default());
+            if part == Part::B {
+                // Part b runs with the debug switch off
+                cpu.registers.set(vm::register('a'), 1);
+            }
+            session.run(&mut cpu)
+        }
+        _ => Err(format_err!("Day {} part {} has no assembly program", day, part)),
+    }
+}
+
+struct Repl<'w, R, W: 'w> {
+    input: R,
+    output: &'w mut W,
+}
+
+impl<'w, R: BufRead, W: Write> Session for Repl<'w, R, W> {
+    type Output = ();
+
+    fn run<D: Debuggee>(self, target: &mut D) -> Result<(), Error> {
+        Debugger::new().repl(target, self.input, self.output)
+    }
+}
+
+/// Debugs the program of `day` as run by `part`, reading commands from `input`.
+pub fn debug<R: BufRead, W: Write>(
+    day: u8,
+    part: Part,
+    program: &str,
+    input: R,
+    output: &mut W,
+) -> Result<(), Error> {
+    start(day, part, program, Repl { input, output })
+}
+
+#[cfg(all(test, feature = "day18"))]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    const PROGRAM: &str = "set a 1
+add a 2
+mul a a
+mod a 5
+snd a
+set a 0
+rcv a
+jgz a -1
+set a 1
+jgz a -2";
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!(Command::Step(1), "".parse::<Command>().unwrap());
+        assert_eq!(Command::Step(5), "s 5".parse::<Command>().unwrap());
+        assert_eq!(
+            Command::Break(Breakpoint::When(2, Comparison::Ge, -3)),
+            "break c >= -3".parse::<Command>().unwrap()
+        );
+        assert_eq!(
+            Command::Break(Breakpoint::Watch(0)),
+            "w a".parse::<Command>().unwrap()
+        );
+        assert!("break c =< 3".parse::<Command>().is_err());
+        assert!("watch 1".parse::<Command>().is_err());
+        assert!("continue 3".parse::<Command>().is_err());
+    }
+
+    #[test]
+    fn test_run() {
+        let program = day18::parse(PROGRAM).unwrap();
+        let mut cpu = Cpu::new(&program, Sound::default());
+        let mut debugger = Debugger::new();
+
+        assert_eq!(Some(Stop::Stepped), debugger.run(&mut cpu, Some(2)).ok());
+        assert_eq!(2, cpu.ip);
+
+        debugger.breakpoints = vec![Breakpoint::At(6), Breakpoint::Watch(0)];
+        assert_eq!(
+            Some(Stop::Watchpoint {
+                program: 0,
+                register: 0,
+                old: 3,
+                new: 9,
+            }),
+            debugger.run(&mut cpu, None).ok()
+        );
+        debugger.breakpoints.remove(1);
+        assert_eq!(
+            Some(Stop::Breakpoint {
+                program: 0,
+                number: 1,
+            }),
+            debugger.run(&mut cpu, None).ok()
+        );
+        assert_eq!(6, cpu.ip);
+
+        debugger.breakpoints = vec![Breakpoint::When(0, Comparison::Eq, 1)];
+        assert_eq!(
+            Some(Stop::Breakpoint {
+                program: 0,
+                number: 1,
+            }),
+            debugger.run(&mut cpu, None).ok()
+        );
+        assert_eq!(9, cpu.ip);
+    }
+
+    #[test]
+    fn test_continue_past_waiting() {
+        let program = day18::parse("snd 1\nrcv a\nrcv a\nset b 1").unwrap();
+        let mut duo = Duo::new(&program);
+        let mut debugger = Debugger::new();
+        debugger.breakpoints = vec![Breakpoint::At(2)];
+
+        assert_eq!(
+            Some(Stop::Breakpoint {
+                program: 0,
+                number: 1,
+            }),
+            debugger.run(&mut duo, None).ok()
+        );
+        // Both wait at the second rcv, which does not hit the breakpoint again
+        assert_eq!(Some(Stop::Ended), debugger.run(&mut duo, None).ok());
+        assert_eq!([2, 2], [duo.cpus[0].ip, duo.cpus[1].ip]);
+    }
+
+    #[test]
+    fn test_run_deadlock() {
+        let program = day18::parse("snd 1\nrcv a\nrcv a").unwrap();
+        let mut duo = Duo::new(&program);
+
+        assert_eq!(Some(Stop::Ended), Debugger::new().run(&mut duo, None).ok());
+        assert_eq!([2, 2], [duo.cpus[0].ip, duo.cpus[1].ip]);
+    }
+
+    #[test]
+    fn test_repl() {
+        let mut output = Vec::new();
+        let commands = "break 4\nc\nr\nbreak z > 1\nd 2\nlist\nstep 2\nq\nstep";
+
+        debug(18, Part::B, PROGRAM, Cursor::new(commands), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.starts_with("#0    0  set a 1\n#1    0  set a 1\n1: break at 4\n"));
+        assert!(output.contains("#0 hit breakpoint 1\n#0    4  snd a\n#1    4  snd a\n"));
+        assert!(output.contains("#1 a=4 b=0 "));
+        assert!(output.contains("#1 Duet { inbox: [], outbox: [], sent: 0 }\n"));
+        assert!(output.contains("deleted break when z > 1\n"));
+        assert!(output.contains("*   4  snd a           #0 #1\n"));
+        assert!(output.ends_with("#0    6  rcv a\n#1    6  rcv a\n"));
+    }
+}
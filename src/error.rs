@@ -0,0 +1,185 @@
+use failure::Fail;
+use std::fmt;
+
+/// Error of a day: either its input is malformed, or it cannot be solved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// `line` and `column` start at 1, `column` counting characters. `source` is the text
+    /// of the line, if known, shown along with the error.
+    Parse {
+        day: u8,
+        line: usize,
+        column: usize,
+        message: String,
+        source: String,
+    },
+    Runtime { day: u8, message: String },
+}
+
+impl Error {
+    pub fn parse<S: Into<String>>(day: u8, line: usize, column: usize, message: S) -> Error {
+        Error::Parse {
+            day,
+            line,
+            column,
+            message: message.into(),
+            source: String::new(),
+        }
+    }
+
+    /// Attaches the text of the line a parse error points at.
+    pub fn with_source(self, text: &str) -> Error {
+        match self {
+            Error::Parse {
+                day,
+                line,
+                column,
+                message,
+                ..
+            } => Error::Parse {
+                day,
+                line,
+                column,
+                message,
+                source: String::from(text),
+            },
+            runtime => runtime,
+        }
+    }
+
+    pub fn runtime<S: Into<String>>(day: u8, message: S) -> Error {
+        Error::Runtime {
+            day,
+            message: message.into(),
+        }
+    }
+
+    pub fn day(&self) -> u8 {
+        match *self {
+            Error::Parse { day, .. } | Error::Runtime { day, .. } => day,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Parse {
+                day,
+                line,
+                column,
+                ref message,
+                ref source,
+            } => {
+                write!(f, "day {}, line {}, column {}: {}", day, line, column, message)?;
+
+                if source.is_empty() {
+                    return Ok(());
+                }
+
+                let (snippet, caret) = snippet(source, column);
+                let gutter = line.to_string().len();
+                write!(f, "\n{0} | {1}\n{2:3$} | {4}^", line, snippet, "", gutter, caret)
+            }
+            Error::Runtime { day, ref message } => write!(f, "day {}: {}", day, message),
+        }
+    }
+}
+
+impl Fail for Error {}
+
+/// Number of characters of a line shown around the column an error points at.
+const SNIPPET_WIDTH: usize = 72;
+
+/// Part of `source` around `column`, and what to print before a caret pointing at it.
+fn snippet(source: &str, column: usize) -> (String, String) {
+    let chars: Vec<char> = source.chars().collect();
+    let column = column.max(1).min(chars.len() + 1);
+
+    let start = (column - 1).saturating_sub(SNIPPET_WIDTH / 2);
+    let end = (start + SNIPPET_WIDTH).min(chars.len());
+
+    let mut snippet: String = chars[start..end].iter().collect();
+    let mut caret: String = chars[start..column - 1]
+        .iter()
+        .map(|&c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+
+    if start > 0 {
+        snippet.insert_str(0, "...");
+        caret.insert_str(0, "   ");
+    }
+    if end < chars.len() {
+        snippet.push_str("...");
+    }
+
+    (snippet, caret)
+}
+
+/// Column of `token`, which must be a slice of `line`, as expected by `Error::parse`.
+pub fn column(line: &str, token: &str) -> usize {
+    let (start, offset) = (line.as_ptr() as usize, token.as_ptr() as usize);
+
+    if offset < start || offset - start > line.len() {
+        return 1;
+    }
+
+    line[..offset - start].chars().count() + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            "day 24, line 3, column 5: invalid port: \"x\"",
+            Error::parse(24, 3, 5, "invalid port: \"x\"").to_string()
+        );
+        assert_eq!(
+            "day 25: no part b",
+            Error::runtime(25, "no part b").to_string()
+        );
+    }
+
+    #[test]
+    fn test_display_source() {
+        assert_eq!(
+            "day 24, line 12, column 3: invalid port: \"x\"
+12 | 2/x
+   |   ^",
+            Error::parse(24, 12, 3, "invalid port: \"x\"")
+                .with_source("2/x")
+                .to_string()
+        );
+        assert_eq!(
+            "day 2, line 1, column 5: oops
+1 | 5\t1\t9
+  |  \t \t^",
+            Error::parse(2, 1, 5, "oops")
+                .with_source("5\t1\t9")
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_snippet() {
+        let source: String = (0..100).map(|i| if i == 80 { 'x' } else { '.' }).collect();
+        let (snippet, caret) = snippet(&source, 81);
+
+        assert_eq!(Some(39), snippet.find('x'));
+        assert_eq!(39, caret.len());
+        assert!(snippet.starts_with("...") && snippet.ends_with('.'));
+    }
+
+    #[test]
+    fn test_column() {
+        let line = String::from("é inc 5 if a > 1");
+        let token = line.split_whitespace().nth(2).unwrap();
+
+        assert_eq!(7, column(&line, token));
+        assert_eq!(1, column(&line, &line));
+        assert_eq!(1, column(&line, &String::from("inc")));
+    }
+}
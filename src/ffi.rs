@@ -0,0 +1,129 @@
+//! C ABI of the knot hash, declared in `include/knot_hash.h` and built as a shared library by
+//! the `knot-hash-ffi` crate.
+
+use knot::KnotHashConfig;
+use std::os::raw::{c_char, c_int};
+use std::slice;
+
+/// Writes the 16 bytes of the knot hash of the `len` bytes at `data` to `out`.
+///
+/// Returns 0 on success, and -1 without writing anything when `out` is null, or `data` is null
+/// while `len` is not 0.
+///
+/// # Safety
+///
+/// `data` must point to `len` readable bytes, or may be null when `len` is 0. `out` must point
+/// to 16 writable bytes which do not overlap them.
+#[no_mangle]
+pub unsafe extern "C" fn knot_hash_bytes(data: *const u8, len: usize, out: *mut u8) -> c_int {
+    let hash = match dense_hash(data, len) {
+        Some(hash) => hash,
+        None => return -1,
+    };
+    if out.is_null() {
+        return -1;
+    }
+
+    let out = slice::from_raw_parts_mut(out, 16);
+    for (byte, n) in out.iter_mut().zip(hash) {
+        *byte = n as u8;
+    }
+
+    0
+}
+
+/// Writes the knot hash of the `len` bytes at `data` to `out` as 32 lowercase hexadecimal
+/// digits followed by a NUL.
+///
+/// Returns 0 on success, and -1 without writing anything when `out` is null, or `data` is null
+/// while `len` is not 0.
+///
+/// # Safety
+///
+/// `data` must point to `len` readable bytes, or may be null when `len` is 0. `out` must point
+/// to a buffer of at least 33 writable chars which does not overlap them.
+#[no_mangle]
+pub unsafe extern "C" fn knot_hash_hex(data: *const u8, len: usize, out: *mut c_char) -> c_int {
+    let hash = match dense_hash(data, len) {
+        Some(hash) => hash,
+        None => return -1,
+    };
+    if out.is_null() {
+        return -1;
+    }
+
+    let out = slice::from_raw_parts_mut(out, 33);
+    let hex = hash.iter().map(|n| format!("{:02x}", n)).collect::<String>();
+    for (c, &b) in out.iter_mut().zip(hex.as_bytes()) {
+        *c = b as c_char;
+    }
+    out[32] = 0;
+
+    0
+}
+
+/// Dense hash of the input, `data` being allowed to be null when `len` is 0.
+///
+/// # Safety
+///
+/// Unless null, `data` must point to `len` readable bytes.
+unsafe fn dense_hash(data: *const u8, len: usize) -> Option<Vec<usize>> {
+    let bytes = match (data.is_null(), len) {
+        (true, 0) => &[],
+        (true, _) => return None,
+        (false, _) => slice::from_raw_parts(data, len),
+    };
+
+    Some(KnotHashConfig::default().hash(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+    use std::ptr;
+
+    // Calls through the exported symbols rather than the Rust functions
+    mod c {
+        use std::os::raw::{c_char, c_int};
+
+        extern "C" {
+            pub fn knot_hash_bytes(data: *const u8, len: usize, out: *mut u8) -> c_int;
+            pub fn knot_hash_hex(data: *const u8, len: usize, out: *mut c_char) -> c_int;
+        }
+    }
+
+    #[test]
+    fn test_knot_hash_bytes() {
+        let input = b"AoC 2017";
+        let mut out = [0u8; 16];
+
+        assert_eq!(0, unsafe { c::knot_hash_bytes(input.as_ptr(), input.len(), out.as_mut_ptr()) });
+        assert_eq!(::knot_digest(input).0, out);
+
+        assert_eq!(0, unsafe { c::knot_hash_bytes(ptr::null(), 0, out.as_mut_ptr()) });
+        assert_eq!(::knot_digest(b"").0, out);
+    }
+
+    #[test]
+    fn test_knot_hash_hex() {
+        let input = b"1,2,3";
+        let mut out = [1 as c_char; 40];
+
+        assert_eq!(0, unsafe { c::knot_hash_hex(input.as_ptr(), input.len(), out.as_mut_ptr()) });
+        assert_eq!(
+            "3efbe78a8d82f29979031a4aa0b16a9d",
+            unsafe { CStr::from_ptr(out.as_ptr()) }.to_str().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_null_pointers() {
+        let mut out = [0u8; 16];
+
+        assert_eq!(-1, unsafe { c::knot_hash_bytes(ptr::null(), 1, out.as_mut_ptr()) });
+        assert_eq!(-1, unsafe { c::knot_hash_bytes(b"a".as_ptr(), 1, ptr::null_mut()) });
+        assert_eq!(-1, unsafe { c::knot_hash_hex(b"a".as_ptr(), 1, ptr::null_mut()) });
+        assert_eq!([0; 16], out);
+    }
+}
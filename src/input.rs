@@ -0,0 +1,75 @@
+use failure::Error;
+use std::env;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Path of the puzzle input of `day` shipped with the repository, usable from any directory.
+pub fn default_path(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("input")
+        .join(format!("day{}.txt", day))
+}
+
+/// Reads and normalizes the input at `path`, `-` being the standard input.
+pub fn read<P: AsRef<Path>>(path: P) -> Result<String, Error> {
+    let path = path.as_ref();
+    let mut input = String::new();
+
+    if path == Path::new("-") {
+        io::stdin().read_to_string(&mut input)?;
+    } else {
+        File::open(path)
+            .and_then(|mut file| file.read_to_string(&mut input))
+            .map_err(|err| format_err!("{}: {}", path.display(), err))?;
+    }
+
+    Ok(normalize(&input))
+}
+
+/// Converts CRLF and CR line endings to LF, drops a byte order mark and the trailing whitespace
+/// of the input, so that no parser sees a final empty line.
+///
+/// Leading whitespace is kept as the day 19 diagram starts with spaces.
+pub fn normalize(input: &str) -> String {
+    input
+        .trim_left_matches('\u{feff}')
+        .replace("\r\n", "\n")
+        .replace('\r', "\n")
+        .trim_right()
+        .to_owned()
+}
+
+/// Reads the input given on the command line of a `dayN` binary: `--input <path>` or `-` for
+/// the standard input, the repository input of `day` otherwise.
+pub fn from_args(day: u8) -> Result<String, Error> {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.len() {
+        0 => read(default_path(day)),
+        1 if args[0] == "-" => read("-"),
+        2 if args[0] == "--input" => read(&args[1]),
+        _ => Err(format_err!("Usage: day{} [--input <path>|-]", day)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!("1122", normalize("1122\n"));
+        assert_eq!("1122", normalize("1122"));
+        assert_eq!("a\nb", normalize("a\r\nb\r\n\r\n"));
+        assert_eq!("a\nb", normalize("a\rb"));
+        assert_eq!("a", normalize("\u{feff}a\n"));
+        assert_eq!("     |\n     A", normalize("     |\n     A  \n"));
+    }
+
+    #[test]
+    fn test_default_path() {
+        assert!(default_path(1).ends_with("input/day1.txt"));
+        assert!(default_path(1).is_absolute());
+    }
+}
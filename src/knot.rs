@@ -0,0 +1,769 @@
+//! Knot hash of days 10 and 14.
+//!
+//! `KnotState`, `knot_digest` and `KnotDigest` only need `core`. What allocates is behind the
+//! `alloc` feature, and what needs threads, I/O or `failure` behind the `std` feature.
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::string::String;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "alloc")]
+use core::hash::{BuildHasher, Hasher};
+#[cfg(feature = "std")]
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use failure::Error;
+#[cfg(feature = "std")]
+use std::io;
+#[cfg(feature = "std")]
+use std::thread;
+
+/// Lengths appended to those of the input by the knot hash of day 10 part b.
+pub const SUFFIX: [u8; 5] = [17, 31, 73, 47, 23];
+
+/// Rounds of the knot hash of day 10 part b.
+pub const ROUNDS: usize = 64;
+
+#[cfg(feature = "alloc")]
+pub fn knot_hash_partial(input: &[usize], list_size: usize, rounds: usize) -> Vec<usize> {
+    let mut list: Vec<_> = (0..list_size).collect();
+    let mut start = 0;
+    let mut step = 0;
+
+    for _ in 0..rounds {
+        for n in input.iter() {
+            for i in 0..n / 2 {
+                list.swap((start + i) % list_size, (start + n - i - 1) % list_size);
+            }
+            start += n + step;
+            step += 1;
+        }
+    }
+
+    list
+}
+
+/// Knots tied one at a time for each of `lengths`, `rounds` times, ending on the list of
+/// `knot_hash_partial`.
+#[cfg(feature = "alloc")]
+pub fn knot_steps(lengths: &[usize], list_size: usize, rounds: usize) -> KnotSteps {
+    KnotSteps {
+        lengths,
+        list: (0..list_size).collect(),
+        count: 0,
+        total: lengths.len() * rounds,
+        position: 0,
+        skip: 0,
+    }
+}
+
+/// Iterator of the knots tied by `knot_steps`.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct KnotSteps<'a> {
+    lengths: &'a [usize],
+    list: Vec<usize>,
+    count: usize,
+    total: usize,
+    position: usize,
+    skip: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Iterator for KnotSteps<'a> {
+    type Item = KnotStep;
+
+    fn next(&mut self) -> Option<KnotStep> {
+        if self.count >= self.total || self.list.is_empty() {
+            return None;
+        }
+
+        let size = self.list.len();
+        let length = self.lengths[self.count % self.lengths.len()];
+        for i in 0..length / 2 {
+            self.list.swap(
+                (self.position + i) % size,
+                (self.position + length - i - 1) % size,
+            );
+        }
+
+        let step = KnotStep {
+            length,
+            position: self.position,
+            skip: self.skip,
+            list: self.list.clone(),
+            next_position: (self.position + length + self.skip) % size,
+        };
+
+        self.position = step.next_position;
+        self.skip += 1;
+        self.count += 1;
+
+        Some(step)
+    }
+}
+
+/// A knot: `length` elements reversed from `position`, wrapping around the end of the list.
+///
+/// Displayed as in the puzzle, with the reversed elements between parentheses and the next
+/// current position between brackets: `4 3) 0 ([1] 2`.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KnotStep {
+    pub length: usize,
+    pub position: usize,
+    pub skip: usize,
+    /// The list once reversed.
+    pub list: Vec<usize>,
+    pub next_position: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for KnotStep {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let size = self.list.len();
+        let last = (self.position + self.length + size - 1) % size;
+
+        for (i, n) in self.list.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            if self.length > 0 && i == self.position {
+                write!(f, "(")?;
+            }
+
+            if i == self.next_position {
+                write!(f, "[{}]", n)?;
+            } else {
+                write!(f, "{}", n)?;
+            }
+
+            if self.length > 0 && i == last {
+                write!(f, ")")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub fn knot_hash(input: &str) -> String {
+    knot_digest(input.as_bytes()).to_hex()
+}
+
+pub fn knot_digest(bytes: &[u8]) -> KnotDigest {
+    let mut state = KnotState::new();
+    for _ in 0..ROUNDS {
+        state.round(bytes);
+        state.round(&SUFFIX);
+    }
+
+    state.dense()
+}
+
+/// List of 256 numbers being knotted, with the current position and skip size.
+///
+/// Instead of reversing around the end of the list, the list is rotated after each length so
+/// that the current position is always its first element.
+#[derive(Clone, Copy)]
+pub struct KnotState {
+    list: [u8; 256],
+    offset: usize,
+    skip: usize,
+}
+
+impl Default for KnotState {
+    fn default() -> KnotState {
+        let mut list = [0; 256];
+        for (i, n) in list.iter_mut().enumerate() {
+            *n = i as u8;
+        }
+
+        KnotState {
+            list,
+            offset: 0,
+            skip: 0,
+        }
+    }
+}
+
+impl KnotState {
+    pub fn new() -> KnotState {
+        KnotState::default()
+    }
+
+    /// Ties a knot of each of `lengths`. A round of the knot hash being the lengths of the input
+    /// followed by `SUFFIX`, it can be given in several calls.
+    pub fn round(&mut self, lengths: &[u8]) {
+        for &n in lengths {
+            self.list[..n as usize].reverse();
+
+            let shift = (n as usize + self.skip) % 256;
+            self.list.rotate(shift);
+            self.offset = (self.offset + shift) % 256;
+            self.skip = (self.skip + 1) % 256;
+        }
+    }
+
+    /// The list in its original order, the sparse hash once every round is done.
+    pub fn sparse(&self) -> [u8; 256] {
+        let mut list = self.list;
+        list.rotate((256 - self.offset) % 256);
+        list
+    }
+
+    /// XOR of each block of 16 numbers of the sparse hash.
+    pub fn dense(&self) -> KnotDigest {
+        let mut hash = [0; 16];
+        for (i, block) in self.sparse().chunks(16).enumerate() {
+            hash[i] = block.iter().fold(0, |acc, n| acc ^ n);
+        }
+
+        KnotDigest(hash)
+    }
+}
+
+/// Dense hash of the knot hash, whose bits can be used without going through hexadecimal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct KnotDigest(pub [u8; 16]);
+
+impl KnotDigest {
+    /// Lowercase hexadecimal, as given by `knot_hash`.
+    #[cfg(feature = "alloc")]
+    pub fn to_hex(&self) -> String {
+        format!("{}", self)
+    }
+
+    /// The 128 bits, most significant first.
+    pub fn bits(&self) -> Bits {
+        Bits {
+            digest: *self,
+            index: 0,
+        }
+    }
+
+    pub fn count_ones(&self) -> u32 {
+        self.0.iter().map(|b| b.count_ones()).sum()
+    }
+
+    /// The bytes read as a big-endian number.
+    pub fn as_u128(&self) -> u128 {
+        self.0.iter().fold(0, |acc, &b| acc << 8 | u128::from(b))
+    }
+}
+
+impl From<[u8; 16]> for KnotDigest {
+    fn from(bytes: [u8; 16]) -> KnotDigest {
+        KnotDigest(bytes)
+    }
+}
+
+/// Lowercase hexadecimal, without allocating.
+impl fmt::Display for KnotDigest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for n in self.0.iter() {
+            write!(f, "{:02x}", n)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Bits of a `KnotDigest`, as returned by `KnotDigest::bits`.
+#[derive(Debug, Clone)]
+pub struct Bits {
+    digest: KnotDigest,
+    index: usize,
+}
+
+impl Iterator for Bits {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<bool> {
+        if self.index >= 128 {
+            return None;
+        }
+
+        let byte = self.digest.0[self.index / 8];
+        let bit = byte >> (7 - self.index % 8) & 1 == 1;
+        self.index += 1;
+
+        Some(bit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = 128 - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Bits {}
+
+/// Knot hash of bytes fed in any number of pieces, the result being the same as if they
+/// were given at once.
+///
+/// Every round replays all the lengths, so the bytes are kept until the hash is finalized.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Default)]
+pub struct KnotHasher {
+    bytes: Vec<u8>,
+}
+
+#[cfg(feature = "alloc")]
+impl KnotHasher {
+    pub fn new() -> KnotHasher {
+        KnotHasher::default()
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    pub fn finalize(self) -> [u8; 16] {
+        self.digest().0
+    }
+
+    pub fn digest(self) -> KnotDigest {
+        knot_digest(&self.bytes)
+    }
+}
+
+/// Knot hashes of `inputs`, in the same order, computed on `jobs` threads when there are
+/// more than 1.
+#[cfg(feature = "std")]
+pub fn knot_hash_many<T: AsRef<[u8]>>(inputs: &[T], jobs: usize) -> Vec<KnotDigest> {
+    if jobs <= 1 || inputs.len() <= 1 {
+        return inputs.iter().map(|input| knot_digest(input.as_ref())).collect();
+    }
+
+    let workers: Vec<_> = inputs
+        .chunks(div_ceil(inputs.len(), jobs))
+        .map(|chunk| {
+            // Each thread owns the inputs it hashes
+            let chunk: Vec<Vec<u8>> = chunk.iter().map(|input| input.as_ref().to_vec()).collect();
+            thread::spawn(move || {
+                chunk
+                    .iter()
+                    .map(|input| knot_digest(input))
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect();
+
+    workers
+        .into_iter()
+        .flat_map(|worker| worker.join().expect("A knot hash thread panicked"))
+        .collect()
+}
+
+/// `a / b`, rounded up.
+#[cfg(feature = "std")]
+fn div_ceil(a: usize, b: usize) -> usize {
+    a / b + if a % b == 0 { 0 } else { 1 }
+}
+
+/// How `KnotHashConfig::encode` writes each number of the dense hash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    Hex,
+    Binary,
+    Decimal,
+}
+
+#[cfg(feature = "std")]
+impl FromStr for Encoding {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Encoding, Error> {
+        match s {
+            "hex" => Ok(Encoding::Hex),
+            "binary" => Ok(Encoding::Binary),
+            "decimal" => Ok(Encoding::Decimal),
+            _ => Err(format_err!("Unknown encoding: {}", s)),
+        }
+    }
+}
+
+/// Parameters of a knot hash, defaulting to those of day 10 part b. With a block size of 1,
+/// no suffix and a single round, the dense hash is the list of part a.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KnotHashConfig {
+    list_size: usize,
+    rounds: usize,
+    suffix: Vec<u8>,
+    block_size: usize,
+    encoding: Encoding,
+}
+
+#[cfg(feature = "alloc")]
+impl Default for KnotHashConfig {
+    fn default() -> KnotHashConfig {
+        KnotHashConfig {
+            list_size: 256,
+            rounds: ROUNDS,
+            suffix: SUFFIX.to_vec(),
+            block_size: 16,
+            encoding: Encoding::Hex,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl KnotHashConfig {
+    pub fn new() -> KnotHashConfig {
+        KnotHashConfig::default()
+    }
+
+    /// Panics if `list_size` is 0.
+    pub fn list_size(mut self, list_size: usize) -> KnotHashConfig {
+        assert!(list_size > 0, "The list of a knot hash cannot be empty");
+        self.list_size = list_size;
+        self
+    }
+
+    pub fn rounds(mut self, rounds: usize) -> KnotHashConfig {
+        self.rounds = rounds;
+        self
+    }
+
+    /// Lengths appended to those of the input.
+    pub fn suffix(mut self, suffix: &[u8]) -> KnotHashConfig {
+        self.suffix = suffix.to_vec();
+        self
+    }
+
+    /// Number of elements of the sparse hash XORed into each number of the dense hash, the
+    /// last block being shorter if it does not divide the list size. Panics if 0.
+    pub fn block_size(mut self, block_size: usize) -> KnotHashConfig {
+        assert!(block_size > 0, "The blocks of a knot hash cannot be empty");
+        self.block_size = block_size;
+        self
+    }
+
+    pub fn encoding(mut self, encoding: Encoding) -> KnotHashConfig {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Dense hash of `bytes`, one number per block.
+    pub fn hash(&self, bytes: &[u8]) -> Vec<usize> {
+        let input: Vec<_> = bytes
+            .iter()
+            .chain(self.suffix.iter())
+            .map(|&b| usize::from(b))
+            .collect();
+
+        knot_hash_partial(&input, self.list_size, self.rounds)
+            .chunks(self.block_size)
+            .map(|chunk| chunk.iter().fold(0, |acc, n| acc ^ n))
+            .collect()
+    }
+
+    /// Dense hash of `bytes` written with the encoding, each number being padded to the width
+    /// of the largest XOR of elements of the list.
+    pub fn encode(&self, bytes: &[u8]) -> String {
+        // Every bit of the largest element can be set, whichever the list size
+        let max = self.list_size.next_power_of_two() - 1;
+        let hash = self.hash(bytes).into_iter();
+
+        match self.encoding {
+            Encoding::Hex => {
+                let width = format!("{:x}", max).len();
+                hash.map(|n| format!("{:01$x}", n, width)).collect()
+            }
+            Encoding::Binary => {
+                let width = format!("{:b}", max).len();
+                hash.map(|n| format!("{:01$b}", n, width)).collect()
+            }
+            Encoding::Decimal => {
+                let width = format!("{}", max).len();
+                hash.map(|n| format!("{:01$}", n, width)).collect()
+            }
+        }
+    }
+}
+
+/// Keeps the first 8 bytes of the hash, so that it can key a `HashMap`.
+#[cfg(feature = "alloc")]
+impl Hasher for KnotHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        knot_digest(&self.bytes).0[..8]
+            .iter()
+            .fold(0, |acc, &b| acc << 8 | u64::from(b))
+    }
+}
+
+/// Lets a reader be hashed with `io::copy`.
+#[cfg(feature = "std")]
+impl io::Write for KnotHasher {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        self.update(bytes);
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Builds `KnotHasher`s, for a `HashMap` or a `HashSet` using the knot hash.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BuildKnotHasher;
+
+#[cfg(feature = "alloc")]
+impl BuildHasher for BuildKnotHasher {
+    type Hasher = KnotHasher;
+
+    fn build_hasher(&self) -> KnotHasher {
+        KnotHasher::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    /// The knot hash as the puzzle tells it, to check the others against: the sublist is copied
+    /// out, reversed and written back.
+    fn reference(lengths: &[usize], list_size: usize, rounds: usize) -> Vec<usize> {
+        let mut list: Vec<_> = (0..list_size).collect();
+        let (mut position, mut skip) = (0, 0);
+
+        for _ in 0..rounds {
+            for &length in lengths {
+                let mut sublist: Vec<_> = (0..length)
+                    .map(|i| list[(position + i) % list_size])
+                    .collect();
+                sublist.reverse();
+
+                for (i, n) in sublist.into_iter().enumerate() {
+                    list[(position + i) % list_size] = n;
+                }
+
+                position = (position + length + skip) % list_size;
+                skip += 1;
+            }
+        }
+
+        list
+    }
+
+    /// Lengths valid for a list of `list_size`, that is up to its size.
+    fn lengths(lengths: &[u8], list_size: usize) -> Vec<usize> {
+        lengths
+            .iter()
+            .map(|&n| usize::from(n) % (list_size + 1))
+            .collect()
+    }
+
+    fn reference_digest(bytes: &[u8]) -> [u8; 16] {
+        let input: Vec<_> = bytes.iter().chain(SUFFIX.iter()).map(|&b| usize::from(b)).collect();
+        let mut hash = [0; 16];
+
+        for (i, block) in reference(&input, 256, ROUNDS).chunks(16).enumerate() {
+            hash[i] = block.iter().fold(0, |acc, n| acc ^ n) as u8;
+        }
+
+        hash
+    }
+
+    quickcheck! {
+        fn test_prop_knot_hash_partial(input: Vec<u8>, list_size: u16, rounds: u8) -> bool {
+            let list_size = usize::from(list_size) % 300 + 1;
+            let rounds = usize::from(rounds % 4);
+            let lengths = lengths(&input, list_size);
+
+            knot_hash_partial(&lengths, list_size, rounds) == reference(&lengths, list_size, rounds)
+        }
+
+        fn test_prop_knot_steps(input: Vec<u8>, list_size: u8, rounds: u8) -> bool {
+            let list_size = usize::from(list_size) + 1;
+            let rounds = usize::from(rounds % 3) + 1;
+            let lengths = lengths(&input, list_size);
+            let last = knot_steps(&lengths, list_size, rounds)
+                .last()
+                .map_or_else(|| (0..list_size).collect(), |step| step.list);
+
+            last == reference(&lengths, list_size, rounds)
+        }
+
+        fn test_prop_knot_state(input: Vec<u8>, rounds: u8) -> bool {
+            let rounds = usize::from(rounds % 4);
+            let mut state = KnotState::new();
+            for _ in 0..rounds {
+                state.round(&input);
+            }
+
+            let lengths: Vec<_> = input.iter().map(|&n| usize::from(n)).collect();
+            let sparse: Vec<_> = state.sparse().iter().map(|&n| usize::from(n)).collect();
+
+            sparse == reference(&lengths, 256, rounds)
+        }
+
+        fn test_prop_knot_digest(input: Vec<u8>, split: usize) -> bool {
+            let digest = knot_digest(&input);
+            let mut hasher = KnotHasher::new();
+            let split = if input.is_empty() { 0 } else { split % input.len() };
+            hasher.update(&input[..split]);
+            hasher.update(&input[split..]);
+
+            digest.0 == reference_digest(&input)
+                && KnotHashConfig::new().encode(&input) == digest.to_hex()
+                && hasher.digest() == digest
+        }
+
+        fn test_prop_knot_hash_many(inputs: Vec<Vec<u8>>, jobs: u8) -> bool {
+            let digests: Vec<_> = inputs.iter().map(|input| knot_digest(input)).collect();
+
+            knot_hash_many(&inputs, usize::from(jobs % 5)) == digests
+        }
+    }
+
+    #[test]
+    fn test_lengths_of_list_size() {
+        for &list_size in &[1, 2, 3, 5, 7, 16, 255, 256] {
+            let lengths = vec![list_size, 1, list_size, 0, list_size];
+
+            assert_eq!(
+                reference(&lengths, list_size, 2),
+                knot_hash_partial(&lengths, list_size, 2)
+            );
+            assert_eq!(
+                reference(&lengths, list_size, 2),
+                knot_steps(&lengths, list_size, 2).last().unwrap().list
+            );
+        }
+
+        let mut state = KnotState::new();
+        state.round(&[255, 1, 255]);
+        assert_eq!(
+            reference(&[255, 1, 255], 256, 1),
+            state.sparse().iter().map(|&n| usize::from(n)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_knot_hash() {
+        assert_eq!("a2582a3a0e66e6e86e3812dcb672a272", knot_hash(""));
+        assert_eq!("33efeb34ea91902bb2f59c9920caa6cd", knot_hash("AoC 2017"));
+    }
+
+    #[test]
+    fn test_knot_steps() {
+        let steps: Vec<_> = knot_steps(&[3, 4, 1, 5], 5, 1).collect();
+
+        assert_eq!(
+            vec!["(2 1 0) [3] 4", "4 3) 0 ([1] 2", "4 [3] 0 (1) 2", "3) (4 2 1 [0]"],
+            steps.iter().map(|step| step.to_string()).collect::<Vec<_>>()
+        );
+        assert_eq!((3, 2), (steps[2].position, steps[2].skip));
+        assert_eq!(
+            knot_hash_partial(&[3, 4, 1, 5, 0], 7, 3),
+            knot_steps(&[3, 4, 1, 5, 0], 7, 3).last().unwrap().list
+        );
+    }
+
+    #[test]
+    fn test_state() {
+        let mut state = KnotState::new();
+        state.round(&[3, 4]);
+        state.round(&[1, 200]);
+
+        let expected: Vec<_> = knot_hash_partial(&[3, 4, 1, 200], 256, 1)
+            .into_iter()
+            .map(|n| n as u8)
+            .collect();
+
+        assert_eq!(&expected[..], &state.sparse()[..]);
+        assert_eq!("33efeb34ea91902bb2f59c9920caa6cd", knot_digest(b"AoC 2017").to_string());
+    }
+
+    #[test]
+    fn test_digest() {
+        let digest = knot_digest(b"AoC 2017");
+
+        assert_eq!("33efeb34ea91902bb2f59c9920caa6cd", digest.to_hex());
+        assert_eq!(0x33ef_eb34_ea91_902b_b2f5_9c99_20ca_a6cd, digest.as_u128());
+        assert_eq!(digest.as_u128().count_ones(), digest.count_ones());
+        assert_eq!(
+            vec![false, false, true, true, false, false, true, true, true, true],
+            digest.bits().take(10).collect::<Vec<_>>()
+        );
+        assert_eq!(128, digest.bits().len());
+        assert_eq!(Some(true), digest.bits().last());
+    }
+
+    #[test]
+    fn test_knot_hash_many() {
+        let inputs: Vec<_> = (0..10).map(|i| format!("flqrgnkx-{}", i)).collect();
+        let config = KnotHashConfig::new();
+
+        for &jobs in &[1, 3, 20] {
+            let digests = knot_hash_many(&inputs, jobs);
+
+            assert_eq!(inputs.len(), digests.len());
+            for (input, digest) in inputs.iter().zip(digests) {
+                assert_eq!(config.encode(input.as_bytes()), digest.to_hex());
+            }
+        }
+        assert!(knot_hash_many::<&str>(&[], 4).is_empty());
+    }
+
+    #[test]
+    fn test_config() {
+        let config = KnotHashConfig::new();
+
+        assert_eq!(knot_hash("AoC 2017"), config.encode(b"AoC 2017"));
+        assert_eq!(
+            format!("{:0128b}", knot_digest(b"AoC 2017").as_u128()),
+            config.encoding(Encoding::Binary).encode(b"AoC 2017")
+        );
+
+        let config = KnotHashConfig::new().list_size(5).rounds(1).suffix(&[]);
+
+        assert_eq!(vec![3, 4, 2, 1, 0], config.clone().block_size(1).hash(&[3, 4, 1, 5]));
+
+        let config = config.block_size(2).encoding(Encoding::Decimal);
+
+        assert_eq!(vec![7, 3, 0], config.hash(&[3, 4, 1, 5]));
+        assert_eq!("730", config.encode(&[3, 4, 1, 5]));
+
+        // 8 ^ 7 is wider than the largest element, 9
+        let config = config.list_size(10);
+
+        assert_eq!(vec![15, 3, 7, 3, 9], config.hash(&[9]));
+        assert_eq!("1503070309", config.encode(&[9]));
+    }
+
+    #[test]
+    fn test_update() {
+        let mut hasher = KnotHasher::new();
+        hasher.update(b"AoC");
+        hasher.update(b"");
+        io::copy(&mut &b" 2017"[..], &mut hasher).unwrap();
+
+        let mut whole = KnotHasher::new();
+        whole.update(b"AoC 2017");
+
+        assert_eq!(whole.finalize(), hasher.clone().finalize());
+        assert_eq!(0x33ef_eb34_ea91_902b, Hasher::finish(&hasher));
+    }
+
+    #[test]
+    fn test_build_hasher() {
+        let mut map = HashMap::with_hasher(BuildKnotHasher);
+        map.insert("a", 1);
+        map.insert("b", 2);
+
+        assert_eq!(Some(&2), map.get("b"));
+    }
+}
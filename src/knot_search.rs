@@ -0,0 +1,294 @@
+//! Brute force search of preimages and collisions of a knot hash, over the strings of an
+//! alphabet up to a maximum length, taken shortest first.
+
+use knot::KnotHashConfig;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Candidates handed to a thread at once.
+const BATCH_SIZE: usize = 256;
+
+/// Counters of a running search, which another thread can read to report its progress.
+#[derive(Debug, Default)]
+pub struct Progress {
+    tried: AtomicUsize,
+    done: AtomicBool,
+}
+
+impl Progress {
+    pub fn new() -> Arc<Progress> {
+        Arc::new(Progress::default())
+    }
+
+    /// Candidates hashed so far.
+    pub fn tried(&self) -> usize {
+        self.tried.load(Ordering::SeqCst)
+    }
+
+    /// Whether the search found what it looked for or ran out of candidates.
+    pub fn is_done(&self) -> bool {
+        self.done.load(Ordering::SeqCst)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Search {
+    pub config: KnotHashConfig,
+    pub alphabet: Vec<u8>,
+    pub max_length: usize,
+    pub jobs: usize,
+}
+
+impl Search {
+    /// Number of candidates, from the empty string to those of `max_length` bytes, saturating
+    /// at `usize::MAX`.
+    pub fn candidates(&self) -> usize {
+        let mut count = 1usize;
+        let mut of_length = 1usize;
+
+        for _ in 0..self.max_length {
+            of_length = of_length.saturating_mul(self.alphabet.len());
+            count = count.saturating_add(of_length);
+        }
+
+        count
+    }
+
+    /// The candidate at `index`, in order of length then of the alphabet.
+    pub fn candidate(&self, mut index: usize) -> Vec<u8> {
+        let base = self.alphabet.len();
+        let mut length = 0;
+        let mut of_length = 1;
+
+        while index >= of_length {
+            index -= of_length;
+            length += 1;
+            of_length = of_length.saturating_mul(base);
+        }
+
+        let mut candidate = vec![0; length];
+        for byte in candidate.iter_mut().rev() {
+            *byte = self.alphabet[index % base];
+            index /= base;
+        }
+
+        candidate
+    }
+
+    /// The first candidate whose encoded hash starts with `prefix`.
+    pub fn preimage(&self, prefix: &str, progress: &Arc<Progress>) -> Option<Vec<u8>> {
+        let found: Arc<Mutex<Option<(usize, Vec<u8>)>>> = Arc::new(Mutex::new(None));
+        let prefix = prefix.to_ascii_lowercase();
+
+        {
+            let found = found.clone();
+            self.run(progress, move |index, candidate, hash| {
+                if !hash.starts_with(&prefix) {
+                    return None;
+                }
+
+                let mut found = found.lock().unwrap();
+                if found.as_ref().map_or(true, |&(other, _)| index < other) {
+                    *found = Some((index, candidate.to_vec()));
+                }
+                Some(index)
+            });
+        }
+
+        let found = found.lock().unwrap().take();
+        found.map(|(_, candidate)| candidate)
+    }
+
+    /// Two different candidates whose encoded hashes have the same first `length` characters,
+    /// an alphabet with a repeated byte giving some candidates twice.
+    pub fn collision(
+        &self,
+        length: usize,
+        progress: &Arc<Progress>,
+    ) -> Option<(Vec<u8>, Vec<u8>)> {
+        let seen: Arc<Mutex<HashMap<String, (usize, Vec<u8>)>>> =
+            Arc::new(Mutex::new(HashMap::new()));
+        let found = Arc::new(Mutex::new(None));
+
+        {
+            let found = found.clone();
+            self.run(progress, move |index, candidate, hash| {
+                let truncated: String = hash.chars().take(length).collect();
+                let mut seen = seen.lock().unwrap();
+
+                match seen.get(&truncated) {
+                    Some(&(other, ref bytes)) if &bytes[..] != candidate => {
+                        // A pair is found once its later candidate is, whichever was hashed first
+                        let pair = if other < index {
+                            (index, bytes.clone(), candidate.to_vec())
+                        } else {
+                            (other, candidate.to_vec(), bytes.clone())
+                        };
+
+                        let mut found = found.lock().unwrap();
+                        let later = pair.0;
+                        if found.as_ref().map_or(true, |&(best, _, _)| later < best) {
+                            *found = Some(pair);
+                        }
+                        return Some(later);
+                    }
+                    Some(_) => return None,
+                    None => {}
+                }
+
+                seen.insert(truncated, (index, candidate.to_vec()));
+                None
+            });
+        }
+
+        let found = found.lock().unwrap().take();
+        found.map(|(_, a, b)| (a, b))
+    }
+
+    /// Hashes the candidates on `jobs` threads until `check`, given the index of a candidate,
+    /// the candidate and its encoded hash, returns the index of a match. Every candidate before
+    /// the lowest of those is still hashed, so that the first match wins whichever thread
+    /// finds it.
+    fn run<F>(&self, progress: &Arc<Progress>, check: F)
+    where
+        F: Fn(usize, &[u8], &str) -> Option<usize> + Send + Sync + 'static,
+    {
+        let search = Arc::new(self.clone());
+        let check = Arc::new(check);
+        let next = Arc::new(AtomicUsize::new(0));
+        let count = self.candidates();
+        // Candidates from this index on need not be hashed
+        let end = Arc::new(AtomicUsize::new(count));
+
+        let workers: Vec<_> = (0..self.jobs.max(1))
+            .map(|_| {
+                let (search, check, next, end, progress) = (
+                    search.clone(),
+                    check.clone(),
+                    next.clone(),
+                    end.clone(),
+                    progress.clone(),
+                );
+
+                thread::spawn(move || loop {
+                    let start = next.fetch_add(BATCH_SIZE, Ordering::SeqCst);
+                    if start >= end.load(Ordering::SeqCst) {
+                        break;
+                    }
+
+                    for index in start..(start + BATCH_SIZE).min(count) {
+                        if index >= end.load(Ordering::SeqCst) {
+                            break;
+                        }
+
+                        let candidate = search.candidate(index);
+                        let hash = search.config.encode(&candidate);
+                        progress.tried.fetch_add(1, Ordering::SeqCst);
+
+                        if let Some(found) = check(index, &candidate, &hash) {
+                            lower(&end, found + 1);
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        for worker in workers {
+            if worker.join().is_err() {
+                eprintln!("A search thread panicked");
+            }
+        }
+
+        progress.done.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Lowers `value` to `to`, unless another thread already lowered it further.
+fn lower(value: &AtomicUsize, to: usize) {
+    let mut current = value.load(Ordering::SeqCst);
+
+    while to < current {
+        match value.compare_exchange(current, to, Ordering::SeqCst, Ordering::SeqCst) {
+            Ok(_) => break,
+            Err(previous) => current = previous,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn search(alphabet: &str, max_length: usize, jobs: usize) -> Search {
+        Search {
+            config: KnotHashConfig::new(),
+            alphabet: alphabet.bytes().collect(),
+            max_length,
+            jobs,
+        }
+    }
+
+    #[test]
+    fn test_candidates() {
+        let search = search("ab", 2, 1);
+
+        assert_eq!(7, search.candidates());
+        assert_eq!(
+            vec!["", "a", "b", "aa", "ab", "ba", "bb"],
+            (0..7)
+                .map(|i| String::from_utf8(search.candidate(i)).unwrap())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(usize::max_value(), self::search("ab", 100, 1).candidates());
+    }
+
+    #[test]
+    fn test_preimage() {
+        let search = search("xyz", 3, 3);
+        let prefix = &search.config.encode(b"zy")[..3];
+        let progress = Progress::new();
+
+        let preimage = search.preimage(prefix, &progress).unwrap();
+
+        assert!(search.config.encode(&preimage).starts_with(prefix));
+        assert!(progress.is_done() && progress.tried() > 0);
+    }
+
+    #[test]
+    fn test_collision() {
+        // 21 candidates for 16 hexadecimal digits
+        let search = search("abcd", 2, 2);
+        let (a, b) = search.collision(1, &Progress::new()).unwrap();
+
+        assert_ne!(a, b);
+        assert_eq!(search.config.encode(&a)[..1], search.config.encode(&b)[..1]);
+        assert_eq!(None, search.preimage("no", &Progress::new()));
+    }
+
+    #[test]
+    fn test_shortest_first() {
+        // 585 candidates, handed to the threads in 3 batches
+        let (one, many) = (search("abcdefgh", 3, 1), search("abcdefgh", 3, 4));
+
+        for prefix in &["00", "5a", "ff"] {
+            assert_eq!(
+                one.preimage(prefix, &Progress::new()),
+                many.preimage(prefix, &Progress::new())
+            );
+        }
+        assert_eq!(
+            one.collision(3, &Progress::new()).map(|(_, b)| b),
+            many.collision(3, &Progress::new()).map(|(_, b)| b)
+        );
+    }
+
+    #[test]
+    fn test_collision_repeated_alphabet() {
+        // "a" and "aa" twice, whose hashes differ in their first 4 digits
+        let search = search("aa", 2, 1);
+
+        assert_eq!(None, search.collision(4, &Progress::new()));
+    }
+}
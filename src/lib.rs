@@ -1,32 +1,124 @@
-pub fn knot_hash_partial(input: &[usize], list_size: usize, rounds: usize) -> Vec<usize> {
-    let mut list: Vec<_> = (0..list_size).collect();
-    let mut start = 0;
-    let mut step = 0;
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(all(feature = "alloc", not(feature = "std")), feature(alloc))]
+#![feature(exclusive_range_pattern)]
+#![feature(i128_type)]
+#![feature(iterator_step_by)]
+#![feature(match_default_bindings)]
+#![feature(slice_patterns)]
+#![feature(slice_rotate)]
 
-    for _ in 0..rounds {
-        for n in input.iter() {
-            for i in 0..n / 2 {
-                list.swap((start + i) % list_size, (start + n - i - 1) % list_size);
-            }
-            start += n + step;
-            step += 1;
-        }
-    }
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+#[macro_use]
+extern crate alloc;
+#[cfg(feature = "bytecount")]
+extern crate bytecount;
+#[cfg(feature = "std")]
+extern crate core;
+#[cfg(feature = "failure")]
+#[macro_use]
+extern crate failure;
+#[cfg(feature = "itertools")]
+extern crate itertools;
+#[cfg(feature = "lazy_static")]
+#[macro_use]
+extern crate lazy_static;
+#[cfg(feature = "primal")]
+extern crate primal;
+#[cfg(test)]
+#[macro_use]
+extern crate quickcheck;
+#[cfg(feature = "regex")]
+extern crate regex;
+#[cfg(feature = "toml")]
+extern crate toml;
 
-    list
-}
+#[cfg(feature = "std")]
+pub mod bench;
+#[cfg(feature = "day1")]
+pub mod day1;
+#[cfg(feature = "day2")]
+pub mod day2;
+#[cfg(feature = "day3")]
+pub mod day3;
+#[cfg(feature = "day4")]
+pub mod day4;
+#[cfg(feature = "day5")]
+pub mod day5;
+#[cfg(feature = "day6")]
+pub mod day6;
+#[cfg(feature = "day7")]
+pub mod day7;
+#[cfg(feature = "day8")]
+pub mod day8;
+#[cfg(feature = "day9")]
+pub mod day9;
+#[cfg(feature = "day10")]
+pub mod day10;
+#[cfg(feature = "day11")]
+pub mod day11;
+#[cfg(feature = "day12")]
+pub mod day12;
+#[cfg(feature = "day13")]
+pub mod day13;
+#[cfg(feature = "day14")]
+pub mod day14;
+#[cfg(feature = "day15")]
+pub mod day15;
+#[cfg(feature = "day16")]
+pub mod day16;
+#[cfg(feature = "day17")]
+pub mod day17;
+#[cfg(feature = "day18")]
+pub mod day18;
+#[cfg(feature = "day19")]
+pub mod day19;
+#[cfg(feature = "day20")]
+pub mod day20;
+#[cfg(feature = "day21")]
+pub mod day21;
+#[cfg(feature = "day22")]
+pub mod day22;
+#[cfg(feature = "day23")]
+pub mod day23;
+#[cfg(feature = "day24")]
+pub mod day24;
+#[cfg(feature = "day25")]
+pub mod day25;
+#[cfg(feature = "std")]
+pub mod debugger;
+#[cfg(feature = "std")]
+pub mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "std")]
+pub mod input;
+#[cfg(feature = "knot-hash")]
+pub mod knot;
+#[cfg(all(feature = "knot-hash", feature = "std"))]
+pub mod knot_search;
+#[cfg(feature = "std")]
+pub mod output;
+#[cfg(feature = "std")]
+pub mod parser;
+#[cfg(feature = "std")]
+pub mod registry;
+#[cfg(feature = "std")]
+pub mod runner;
+#[cfg(feature = "std")]
+pub mod solution;
+#[cfg(feature = "std")]
+pub mod trace;
+#[cfg(feature = "std")]
+pub mod verify;
+#[cfg(feature = "std")]
+pub mod vm;
 
-pub fn knot_hash(input: &str) -> String {
-    let input: Vec<_> = input
-        .bytes()
-        .map(usize::from)
-        .chain(vec![17, 31, 73, 47, 23].into_iter())
-        .collect();
-
-    let list_size = 256;
-    knot_hash_partial(&input, list_size, 64)
-        .chunks(16)
-        .map(|chunk| chunk.iter().fold(0, |acc, n| acc ^ n))
-        .map(|n| format!("{:02x}", n))
-        .collect()
-}
+#[cfg(feature = "knot-hash")]
+pub use knot::{knot_digest, KnotDigest, KnotState};
+#[cfg(all(feature = "knot-hash", feature = "alloc"))]
+pub use knot::{knot_hash, knot_hash_partial, knot_steps, BuildKnotHasher, KnotHashConfig,
+               KnotHasher};
+#[cfg(all(feature = "knot-hash", feature = "std"))]
+pub use knot::knot_hash_many;
+#[cfg(feature = "std")]
+pub use solution::Solution;
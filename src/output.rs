@@ -0,0 +1,147 @@
+use failure::Error;
+use runner::Answer;
+use std::fmt::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Tsv,
+}
+
+impl Default for Format {
+    fn default() -> Format {
+        Format::Text
+    }
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Format, Error> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "tsv" => Ok(Format::Tsv),
+            _ => Err(format_err!("Unknown format: {}", s)),
+        }
+    }
+}
+
+impl Format {
+    /// First line to print before any answer.
+    pub fn header(&self) -> Option<&'static str> {
+        match *self {
+            Format::Tsv => Some("day\tpart\tanswer\telapsed_ms\terror"),
+            Format::Text | Format::Json => None,
+        }
+    }
+
+    pub fn render(&self, answer: &Answer) -> String {
+        match *self {
+            Format::Text => match answer.answer {
+                Ok(ref value) => format!("day {:>2} {}: {}", answer.day, answer.part, value),
+                Err(ref err) => format!("day {:>2} {}: error: {}", answer.day, answer.part, err),
+            },
+            Format::Json => {
+                let (key, value) = match answer.answer {
+                    Ok(ref value) => ("answer", value),
+                    Err(ref err) => ("error", err),
+                };
+                format!(
+                    "{{\"day\":{},\"part\":\"{}\",\"{}\":{},\"elapsed_ms\":{:.3}}}",
+                    answer.day,
+                    answer.part,
+                    key,
+                    json_string(value),
+                    millis(answer.elapsed)
+                )
+            }
+            Format::Tsv => {
+                let (value, err) = match answer.answer {
+                    Ok(ref value) => (tsv_field(value), String::new()),
+                    Err(ref err) => (String::new(), tsv_field(err)),
+                };
+                format!(
+                    "{}\t{}\t{}\t{:.3}\t{}",
+                    answer.day,
+                    answer.part,
+                    value,
+                    millis(answer.elapsed),
+                    err
+                )
+            }
+        }
+    }
+}
+
+pub fn millis(duration: Duration) -> f64 {
+    duration.as_secs() as f64 * 1e3 + f64::from(duration.subsec_nanos()) / 1e6
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::from("\"");
+
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c < ' ' => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+fn tsv_field(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use registry::Part;
+
+    fn answer(answer: Result<&str, &str>) -> Answer {
+        Answer {
+            day: 16,
+            part: Part::B,
+            answer: answer.map(String::from).map_err(String::from),
+            elapsed: Duration::new(1, 500_000),
+        }
+    }
+
+    #[test]
+    fn test_render_text() {
+        assert_eq!("day 16 b: abc", Format::Text.render(&answer(Ok("abc"))));
+        assert_eq!("day 16 b: error: oops", Format::Text.render(&answer(Err("oops"))));
+    }
+
+    #[test]
+    fn test_render_json() {
+        assert_eq!(
+            r#"{"day":16,"part":"b","answer":"abc","elapsed_ms":1000.500}"#,
+            Format::Json.render(&answer(Ok("abc")))
+        );
+        assert_eq!(
+            r#"{"day":16,"part":"b","error":"\"a\"\n\u0001","elapsed_ms":1000.500}"#,
+            Format::Json.render(&answer(Err("\"a\"\n\u{1}")))
+        );
+    }
+
+    #[test]
+    fn test_render_tsv() {
+        assert_eq!("16\tb\tabc\t1000.500\t", Format::Tsv.render(&answer(Ok("abc"))));
+        assert_eq!("16\tb\t\t1000.500\ta\\tb", Format::Tsv.render(&answer(Err("a\tb"))));
+    }
+}
@@ -0,0 +1,191 @@
+use error::{column, Error};
+use std::iter::Enumerate;
+use std::str::{self, FromStr};
+
+/// Numbered lines of the input of a day, as read by `lines`.
+pub struct Lines<'a> {
+    day: u8,
+    lines: Enumerate<str::Lines<'a>>,
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Line<'a>;
+
+    fn next(&mut self) -> Option<Line<'a>> {
+        let day = self.day;
+        self.lines.next().map(|(i, text)| Line {
+            day,
+            number: i + 1,
+            text,
+        })
+    }
+}
+
+pub fn lines(day: u8, input: &str) -> Lines {
+    Lines {
+        day,
+        lines: input.lines().enumerate(),
+    }
+}
+
+/// The only line of an input, empty if the input is.
+pub fn single_line(day: u8, input: &str) -> Result<Line, Error> {
+    let mut lines = lines(day, input);
+    let line = lines.next().unwrap_or(Line {
+        day,
+        number: 1,
+        text: "",
+    });
+
+    match lines.next() {
+        Some(extra) => Err(extra.error_at(1, "expected a single line")),
+        None => Ok(line),
+    }
+}
+
+/// A line of input, which errors point at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub day: u8,
+    /// Starts at 1.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Error pointing at the first character of `token`, which must be a slice of this line.
+    pub fn error<S: Into<String>>(&self, token: &str, message: S) -> Error {
+        self.error_at(column(self.text, token), message)
+    }
+
+    pub fn error_at<S: Into<String>>(&self, column: usize, message: S) -> Error {
+        Error::parse(self.day, self.number, column, message).with_source(self.text)
+    }
+
+    /// Error pointing just past the last character of the line.
+    pub fn error_at_end<S: Into<String>>(&self, message: S) -> Error {
+        self.error_at(self.text.chars().count() + 1, message)
+    }
+
+    /// Parses `token`, a slice of this line, into a `T` described by `expected`.
+    pub fn parse<T: FromStr>(&self, token: &str, expected: &str) -> Result<T, Error> {
+        token
+            .parse()
+            .map_err(|_| self.error(token, format!("expected {}, found {:?}", expected, token)))
+    }
+
+    /// Whitespace separated tokens of the line.
+    pub fn words(&self) -> Tokens<'a> {
+        Tokens {
+            line: *self,
+            tokens: Box::new(self.text.split_whitespace()),
+        }
+    }
+
+    /// Tokens of the line separated by `separator`, and trimmed of surrounding whitespace.
+    pub fn split(&self, separator: &'a str) -> Tokens<'a> {
+        Tokens {
+            line: *self,
+            tokens: Box::new(self.text.split(separator).map(str::trim)),
+        }
+    }
+}
+
+/// Tokens of a line, read one after the other by what they are expected to be.
+pub struct Tokens<'a> {
+    line: Line<'a>,
+    tokens: Box<Iterator<Item = &'a str> + 'a>,
+}
+
+impl<'a> Tokens<'a> {
+    pub fn token(&mut self, expected: &str) -> Result<&'a str, Error> {
+        let line = self.line;
+        self.tokens
+            .next()
+            .ok_or_else(|| line.error_at_end(format!("expected {}", expected)))
+    }
+
+    pub fn parse<T: FromStr>(&mut self, expected: &str) -> Result<T, Error> {
+        let token = self.token(expected)?;
+        self.line.parse(token, expected)
+    }
+
+    /// Skips `literal`, which must be the next token.
+    pub fn literal(&mut self, literal: &str) -> Result<(), Error> {
+        let token = self.token(&format!("`{}`", literal))?;
+
+        if token == literal {
+            Ok(())
+        } else {
+            Err(self.line
+                .error(token, format!("expected `{}`, found {:?}", literal, token)))
+        }
+    }
+
+    /// Checks there is no token left.
+    pub fn end(mut self) -> Result<(), Error> {
+        match self.tokens.next() {
+            Some(token) => Err(self.line.error(token, format!("unexpected {:?}", token))),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        self.tokens.next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines() {
+        let lines: Vec<_> = lines(8, "a\nb").map(|line| (line.number, line.text)).collect();
+
+        assert_eq!(vec![(1, "a"), (2, "b")], lines);
+    }
+
+    #[test]
+    fn test_single_line() {
+        assert_eq!("abc", single_line(9, "abc").unwrap().text);
+        assert_eq!("", single_line(9, "").unwrap().text);
+        assert_eq!(
+            Err(Error::parse(9, 2, 1, "expected a single line").with_source("d")),
+            single_line(9, "abc\nd")
+        );
+    }
+
+    #[test]
+    fn test_tokens() {
+        let line = lines(8, "b inc 5 if a > 1").next().unwrap();
+        let mut words = line.words();
+
+        assert_eq!(Ok("b"), words.token("a register"));
+        assert_eq!(Ok("inc"), words.token("an operation"));
+        assert_eq!(Ok(5), words.parse::<i32>("an offset"));
+        assert_eq!(Ok(()), words.literal("if"));
+        assert_eq!(
+            Err(line.error_at(12, "expected `>`, found \"a\"")),
+            words.literal(">")
+        );
+        assert_eq!(Err(line.error_at(14, "unexpected \">\"")), words.end());
+    }
+
+    #[test]
+    fn test_tokens_errors() {
+        let line = lines(24, "0/ x").next().unwrap();
+        let mut ports = line.split("/");
+
+        assert_eq!(Ok(0), ports.parse::<usize>("a port"));
+        assert_eq!(
+            Err(line.error_at(4, "expected a port, found \"x\"")),
+            ports.parse::<usize>("a port")
+        );
+        assert_eq!(Err(line.error_at(5, "expected a port")), ports.token("a port"));
+    }
+}
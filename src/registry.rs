@@ -0,0 +1,217 @@
+use failure::Error;
+use input;
+use solution::Solution;
+use std::any::Any;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Part::A => f.pad("a"),
+            Part::B => f.pad("b"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Part, Error> {
+        match s {
+            "a" | "A" => Ok(Part::A),
+            "b" | "B" => Ok(Part::B),
+            _ => Err(format_err!("Unknown part: {}", s)),
+        }
+    }
+}
+
+/// Input of a day once parsed, only readable by the solution which produced it.
+pub struct Parsed(Box<Any>);
+
+trait ErasedSolution {
+    fn parse(&self, input: &str) -> Result<Parsed, Error>;
+
+    fn solve(&self, part: Part, input: &Parsed) -> Result<String, Error>;
+}
+
+impl<S> ErasedSolution for S
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn parse(&self, input: &str) -> Result<Parsed, Error> {
+        Ok(Parsed(Box::new(Solution::parse(self, input)?)))
+    }
+
+    fn solve(&self, part: Part, input: &Parsed) -> Result<String, Error> {
+        let input = input
+            .0
+            .downcast_ref::<S::Input>()
+            .ok_or_else(|| format_err!("Input was parsed by another day"))?;
+
+        match part {
+            Part::A => Ok(self.part_a(input)?.to_string()),
+            Part::B => Ok(self.part_b(input)?.to_string()),
+        }
+    }
+}
+
+pub struct Day {
+    pub day: u8,
+    pub parts: &'static [Part],
+    solution: Box<ErasedSolution + Send + Sync>,
+}
+
+impl Day {
+    // Unused when no day feature is enabled.
+    #[allow(dead_code)]
+    fn new<S>(day: u8, parts: &'static [Part], solution: S) -> Day
+    where
+        S: Solution + Send + Sync + 'static,
+        S::Input: 'static,
+    {
+        Day {
+            day,
+            parts,
+            solution: Box::new(solution),
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Parsed, Error> {
+        self.solution.parse(input)
+    }
+
+    pub fn solve(&self, part: Part, input: &Parsed) -> Result<String, Error> {
+        if !self.parts.contains(&part) {
+            return Err(format_err!("Day {} has no part {}", self.day, part));
+        }
+
+        self.solution.solve(part, input)
+    }
+
+    pub fn run(&self, part: Part, input: &str) -> Result<String, Error> {
+        self.solve(part, &self.parse(input)?)
+    }
+
+    pub fn input_path(&self) -> PathBuf {
+        input::default_path(self.day)
+    }
+}
+
+#[allow(dead_code)]
+const BOTH_PARTS: &[Part] = &[Part::A, Part::B];
+
+/// Every day compiled in, as selected by the `dayN` features.
+#[allow(unused_mut)]
+pub fn all() -> Vec<Day> {
+    let mut days = Vec::new();
+
+    #[cfg(feature = "day1")]
+    days.push(Day::new(1, BOTH_PARTS, ::day1::Day1));
+    #[cfg(feature = "day2")]
+    days.push(Day::new(2, BOTH_PARTS, ::day2::Day2));
+    #[cfg(feature = "day3")]
+    days.push(Day::new(3, BOTH_PARTS, ::day3::Day3));
+    #[cfg(feature = "day4")]
+    days.push(Day::new(4, BOTH_PARTS, ::day4::Day4));
+    #[cfg(feature = "day5")]
+    days.push(Day::new(5, BOTH_PARTS, ::day5::Day5));
+    #[cfg(feature = "day6")]
+    days.push(Day::new(6, BOTH_PARTS, ::day6::Day6));
+    #[cfg(feature = "day7")]
+    days.push(Day::new(7, BOTH_PARTS, ::day7::Day7));
+    #[cfg(feature = "day8")]
+    days.push(Day::new(8, BOTH_PARTS, ::day8::Day8));
+    #[cfg(feature = "day9")]
+    days.push(Day::new(9, BOTH_PARTS, ::day9::Day9));
+    #[cfg(feature = "day10")]
+    days.push(Day::new(10, BOTH_PARTS, ::day10::Day10::default()));
+    #[cfg(feature = "day11")]
+    days.push(Day::new(11, BOTH_PARTS, ::day11::Day11));
+    #[cfg(feature = "day12")]
+    days.push(Day::new(12, BOTH_PARTS, ::day12::Day12));
+    #[cfg(feature = "day13")]
+    days.push(Day::new(13, BOTH_PARTS, ::day13::Day13));
+    #[cfg(feature = "day14")]
+    days.push(Day::new(14, BOTH_PARTS, ::day14::Day14::default()));
+    #[cfg(feature = "day15")]
+    days.push(Day::new(15, BOTH_PARTS, ::day15::Day15::default()));
+    #[cfg(feature = "day16")]
+    days.push(Day::new(16, BOTH_PARTS, ::day16::Day16::default()));
+    #[cfg(feature = "day17")]
+    days.push(Day::new(17, BOTH_PARTS, ::day17::Day17::default()));
+    #[cfg(feature = "day18")]
+    days.push(Day::new(18, BOTH_PARTS, ::day18::Day18::default()));
+    #[cfg(feature = "day19")]
+    days.push(Day::new(19, BOTH_PARTS, ::day19::Day19));
+    #[cfg(feature = "day20")]
+    days.push(Day::new(20, BOTH_PARTS, ::day20::Day20));
+    #[cfg(feature = "day21")]
+    days.push(Day::new(21, BOTH_PARTS, ::day21::Day21::default()));
+    #[cfg(feature = "day22")]
+    days.push(Day::new(22, BOTH_PARTS, ::day22::Day22::default()));
+    #[cfg(feature = "day23")]
+    days.push(Day::new(23, BOTH_PARTS, ::day23::Day23));
+    #[cfg(feature = "day24")]
+    days.push(Day::new(24, BOTH_PARTS, ::day24::Day24));
+    #[cfg(feature = "day25")]
+    days.push(Day::new(25, &[Part::A], ::day25::Day25));
+
+    days
+}
+
+pub fn get(day: u8) -> Result<Day, Error> {
+    all()
+        .into_iter()
+        .find(|d| d.day == day)
+        .ok_or_else(|| format_err!("No solution for day {}", day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "all")]
+    #[test]
+    fn test_all() {
+        assert_eq!(25, all().len());
+    }
+
+    #[cfg(feature = "day18")]
+    #[test]
+    fn test_get() {
+        assert_eq!(18, get(18).unwrap().day);
+        assert!(get(0).is_err());
+        assert!(get(26).is_err());
+    }
+
+    #[cfg(all(feature = "day1", feature = "day25"))]
+    #[test]
+    fn test_run() {
+        assert_eq!("3", get(1).unwrap().run(Part::A, "1122").unwrap());
+        assert_eq!("6", get(1).unwrap().run(Part::B, "1212").unwrap());
+        assert!(get(25).unwrap().run(Part::B, "").is_err());
+    }
+
+    #[cfg(all(feature = "day1", feature = "day3"))]
+    #[test]
+    fn test_solve_other_day_input() {
+        let input = get(3).unwrap().parse("12").unwrap();
+        assert!(get(1).unwrap().solve(Part::A, &input).is_err());
+    }
+
+    #[test]
+    fn test_part_from_str() {
+        assert_eq!(Part::A, "a".parse().unwrap());
+        assert_eq!(Part::B, "B".parse().unwrap());
+        assert!("c".parse::<Part>().is_err());
+    }
+}
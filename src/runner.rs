@@ -0,0 +1,150 @@
+use registry::{Day, Part};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Outcome of one part of a day, the error being kept as its message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Answer {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Result<String, String>,
+    pub elapsed: Duration,
+}
+
+/// Parses `input` once and solves each of `parts`, timing every part on its own.
+///
+/// When the input cannot be parsed, every part reports the parse error.
+pub fn run(day: &Day, input: &str, parts: &[Part]) -> Vec<Answer> {
+    let parsed = day.parse(input);
+
+    parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match parsed {
+                Ok(ref parsed) => day.solve(part, parsed).map_err(|err| err.to_string()),
+                Err(ref err) => Err(err.to_string()),
+            };
+
+            Answer {
+                day: day.day,
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect()
+}
+
+/// A day to run, with its raw input and the parts to solve.
+pub struct Task {
+    pub day: Day,
+    pub input: String,
+    pub parts: Vec<Part>,
+}
+
+/// Solves every part of `tasks` on `jobs` threads, each part being a unit of work so that
+/// both parts of a heavy day can run at the same time.
+///
+/// Answers are returned in the order of `tasks` and of their parts, whatever the order in
+/// which they complete.
+pub fn run_parallel(tasks: Vec<Task>, jobs: usize) -> Vec<Answer> {
+    let units: Vec<(usize, Part)> = tasks
+        .iter()
+        .enumerate()
+        .flat_map(|(i, task)| task.parts.iter().map(move |&part| (i, part)))
+        .collect();
+    let count = units.len();
+
+    let tasks = Arc::new(tasks);
+    let units = Arc::new(units);
+    let next = Arc::new(AtomicUsize::new(0));
+    let (sender, receiver) = mpsc::channel();
+
+    let workers: Vec<_> = (0..jobs.max(1).min(count))
+        .map(|_| {
+            let (tasks, units, next, sender) =
+                (tasks.clone(), units.clone(), next.clone(), sender.clone());
+
+            thread::spawn(move || loop {
+                let unit = next.fetch_add(1, Ordering::SeqCst);
+                if unit >= units.len() {
+                    break;
+                }
+
+                let (i, part) = units[unit];
+                let task = &tasks[i];
+                for answer in run(&task.day, &task.input, &[part]) {
+                    if sender.send((unit, answer)).is_err() {
+                        return;
+                    }
+                }
+            })
+        })
+        .collect();
+
+    drop(sender);
+    let mut answers: Vec<_> = receiver.iter().collect();
+
+    for worker in workers {
+        if worker.join().is_err() {
+            eprintln!("A worker thread panicked");
+        }
+    }
+
+    answers.sort_by_key(|&(unit, _)| unit);
+    answers.into_iter().map(|(_, answer)| answer).collect()
+}
+
+#[cfg(all(test, feature = "day1", feature = "day3"))]
+mod tests {
+    use super::*;
+    use registry;
+
+    #[test]
+    fn test_run() {
+        let answers = run(&registry::get(1).unwrap(), "1212", &[Part::A, Part::B]);
+
+        assert_eq!(2, answers.len());
+        assert_eq!(Ok(String::from("0")), answers[0].answer);
+        assert_eq!(Ok(String::from("6")), answers[1].answer);
+        assert_eq!(Part::B, answers[1].part);
+    }
+
+    #[test]
+    fn test_run_parallel() {
+        let tasks = vec![
+            Task {
+                day: registry::get(1).unwrap(),
+                input: String::from("1212"),
+                parts: vec![Part::A, Part::B],
+            },
+            Task {
+                day: registry::get(3).unwrap(),
+                input: String::from("1024"),
+                parts: vec![Part::A],
+            },
+        ];
+
+        let answers = run_parallel(tasks, 3);
+
+        assert_eq!(
+            vec![(1, Part::A), (1, Part::B), (3, Part::A)],
+            answers
+                .iter()
+                .map(|answer| (answer.day, answer.part))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(Ok(String::from("31")), answers[2].answer);
+    }
+
+    #[test]
+    fn test_run_parse_error() {
+        let answers = run(&registry::get(3).unwrap(), "x", &[Part::A, Part::B]);
+
+        assert!(answers.iter().all(|answer| answer.answer.is_err()));
+    }
+}
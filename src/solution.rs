@@ -0,0 +1,22 @@
+use error::Error;
+use std::fmt::Display;
+
+/// A daily puzzle: its input is parsed once, then shared by both parts.
+///
+/// Puzzle constants (list sizes, iteration counts, ...) are fields of the
+/// implementing type, their `Default` being the values of the actual puzzle.
+///
+/// Malformed inputs are reported as `Error::Parse`, and inputs which cannot
+/// be solved as `Error::Runtime`, both naming the day.
+pub trait Solution {
+    type Input;
+    type A: Display;
+    type B: Display;
+
+    /// Parses an input already normalized by `input::normalize`.
+    fn parse(&self, input: &str) -> Result<Self::Input, Error>;
+
+    fn part_a(&self, input: &Self::Input) -> Result<Self::A, Error>;
+
+    fn part_b(&self, input: &Self::Input) -> Result<Self::B, Error>;
+}
@@ -0,0 +1,426 @@
+//! Traces of the assembly of days 18 and 23: each instruction run with the registers it
+//! changed, and a profile of where the programs spend their time.
+
+use debugger::{self, Debuggee, Session};
+use failure::Error;
+use std::collections::HashMap;
+use std::fmt;
+use std::io::Write;
+use vm::{register_name, Instruction, Register, State};
+
+/// A register changed by an instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Change {
+    pub register: Register,
+    pub old: i64,
+    pub new: i64,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}->{}", register_name(self.register), self.old, self.new)
+    }
+}
+
+/// An instruction run by one of the programs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub program: usize,
+    pub ip: usize,
+    pub instruction: Instruction,
+    pub changes: Vec<Change>,
+    /// Where the program goes next, `None` once it left.
+    pub next: Option<usize>,
+}
+
+/// Receives each instruction run by `trace`.
+pub trait TraceSink {
+    fn record(&mut self, event: &Event) -> Result<(), Error>;
+}
+
+impl<'s, T: TraceSink + ?Sized> TraceSink for &'s mut T {
+    fn record(&mut self, event: &Event) -> Result<(), Error> {
+        (**self).record(event)
+    }
+}
+
+impl<T: TraceSink> TraceSink for Option<T> {
+    fn record(&mut self, event: &Event) -> Result<(), Error> {
+        match *self {
+            Some(ref mut sink) => sink.record(event),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<A: TraceSink, B: TraceSink> TraceSink for (A, B) {
+    fn record(&mut self, event: &Event) -> Result<(), Error> {
+        self.0.record(event)?;
+        self.1.record(event)
+    }
+}
+
+/// Steps `target` until it cannot go on, or `steps` ran if given, handing each instruction
+/// which ran to `sink`. Returns whether the programs ended.
+///
+/// An instruction waiting for a value does not run, and is not recorded.
+pub fn trace<D, T>(target: &mut D, sink: &mut T, steps: Option<usize>) -> Result<bool, Error>
+where
+    D: Debuggee,
+    T: TraceSink,
+{
+    let mut taken = 0;
+    let mut before = Vec::new();
+
+    while steps.map_or(true, |steps| taken < steps) {
+        debugger::snapshot(target, &mut before);
+        let running = target.step()?;
+        taken += 1;
+
+        for (program, before) in before.iter().enumerate() {
+            let (ip, state, old) = (before.ip, before.state, &before.registers);
+            let after = target.state(program);
+            if state == State::Halted || after == State::Waiting {
+                continue;
+            }
+
+            let new = target.registers(program);
+            let changes = (0..old.values().len() as Register)
+                .filter(|&r| old.get(r) != new.get(r))
+                .map(|r| Change {
+                    register: r,
+                    old: old.get(r),
+                    new: new.get(r),
+                })
+                .collect();
+
+            sink.record(&Event {
+                program,
+                ip,
+                instruction: target.program()[ip],
+                changes,
+                next: if after == State::Halted {
+                    None
+                } else {
+                    Some(target.ip(program))
+                },
+            })?;
+        }
+
+        if !running {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// Writes each event as a CSV row: `program,ip,instruction,changes,next`, the changes being
+/// separated by spaces.
+pub struct CsvTrace<W> {
+    output: W,
+    started: bool,
+}
+
+impl<W: Write> CsvTrace<W> {
+    pub fn new(output: W) -> CsvTrace<W> {
+        CsvTrace {
+            output,
+            started: false,
+        }
+    }
+}
+
+impl<W: Write> TraceSink for CsvTrace<W> {
+    fn record(&mut self, event: &Event) -> Result<(), Error> {
+        if !self.started {
+            writeln!(self.output, "program,ip,instruction,changes,next")?;
+            self.started = true;
+        }
+
+        let changes: Vec<_> = event.changes.iter().map(Change::to_string).collect();
+        writeln!(
+            self.output,
+            "{},{},{},{},{}",
+            event.program,
+            event.ip,
+            event.instruction,
+            changes.join(" "),
+            event.next.map(|next| next.to_string()).unwrap_or_default()
+        )?;
+
+        Ok(())
+    }
+}
+
+/// A loop of the program: the instructions from `start` to `end`, which jumped back to
+/// `start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Loop {
+    pub start: usize,
+    pub end: usize,
+    /// Jumps back from `end` to `start`.
+    pub iterations: u64,
+    /// Instructions run between `start` and `end`, in this loop or nested ones.
+    pub executed: u64,
+}
+
+/// How many times each instruction ran, and which jumps went backwards.
+#[derive(Debug, Clone, Default)]
+pub struct Profile {
+    counts: Vec<u64>,
+    back_jumps: HashMap<(usize, usize), u64>,
+}
+
+impl TraceSink for Profile {
+    fn record(&mut self, event: &Event) -> Result<(), Error> {
+        if event.ip >= self.counts.len() {
+            self.counts.resize(event.ip + 1, 0);
+        }
+        self.counts[event.ip] += 1;
+
+        match event.next {
+            Some(next) if next <= event.ip => {
+                *self.back_jumps.entry((next, event.ip)).or_insert(0) += 1;
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+impl Profile {
+    pub fn new() -> Profile {
+        Profile::default()
+    }
+
+    /// Times the instruction at `ip` ran.
+    pub fn count(&self, ip: usize) -> u64 {
+        self.counts.get(ip).cloned().unwrap_or(0)
+    }
+
+    /// Instructions run in all.
+    pub fn total(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// Loops which ran, those running the most instructions first.
+    pub fn hot_loops(&self) -> Vec<Loop> {
+        let mut loops: Vec<_> = self.back_jumps
+            .iter()
+            .map(|(&(start, end), &iterations)| Loop {
+                start,
+                end,
+                iterations,
+                executed: (start..end + 1).map(|ip| self.count(ip)).sum(),
+            })
+            .collect();
+
+        loops.sort_by(|a, b| {
+            (b.executed.cmp(&a.executed))
+                .then(a.start.cmp(&b.start))
+                .then(a.end.cmp(&b.end))
+        });
+        loops
+    }
+
+    /// Writes `ip,instruction,count` rows for each instruction of `program`.
+    pub fn write_csv<W: Write>(&self, program: &[Instruction], mut output: W) -> Result<(), Error> {
+        writeln!(output, "ip,instruction,count")?;
+
+        for (ip, instruction) in program.iter().enumerate() {
+            writeln!(output, "{},{},{}", ip, instruction, self.count(ip))?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes `start,end,iterations,executed` rows for each loop, the hottest first.
+    pub fn write_loops_csv<W: Write>(&self, mut output: W) -> Result<(), Error> {
+        writeln!(output, "start,end,iterations,executed")?;
+
+        for l in self.hot_loops() {
+            writeln!(output, "{},{},{},{}", l.start, l.end, l.iterations, l.executed)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes how many times each instruction of `program` ran, then the `loops` hottest loops.
+    pub fn write_summary<W: Write>(
+        &self,
+        program: &[Instruction],
+        loops: usize,
+        mut output: W,
+    ) -> Result<(), Error> {
+        let total = self.total();
+        let share = |count: u64| 100.0 * count as f64 / total.max(1) as f64;
+
+        writeln!(output, "{:>4}  {:<16}  {:>12}  {:>6}", "ip", "instruction", "count", "share")?;
+        for (ip, instruction) in program.iter().enumerate() {
+            writeln!(
+                output,
+                "{:>4}  {:<16}  {:>12}  {:>5.1}%",
+                ip,
+                instruction.to_string(),
+                self.count(ip),
+                share(self.count(ip))
+            )?;
+        }
+        writeln!(output, "{} instruction(s) run", total)?;
+
+        let hot_loops = self.hot_loops();
+        if !hot_loops.is_empty() {
+            writeln!(output, "\nhot loops:")?;
+        }
+        for l in hot_loops.iter().take(loops) {
+            writeln!(
+                output,
+                "{:>4}..{:<4}  {:>12} iteration(s)  {:>12} instruction(s)  {:>5.1}%",
+                l.start,
+                l.end,
+                l.iterations,
+                l.executed,
+                share(l.executed)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Profiles the programs of a day for `steps` if given, recording them to `trace` if given.
+pub struct Profiler<T> {
+    pub steps: Option<usize>,
+    pub trace: Option<T>,
+}
+
+/// What a `Profiler` found.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub program: Vec<Instruction>,
+    pub profile: Profile,
+    /// Whether the programs ended rather than ran out of steps.
+    pub ended: bool,
+}
+
+impl<T: TraceSink> Session for Profiler<T> {
+    type Output = Report;
+
+    fn run<D: Debuggee>(self, target: &mut D) -> Result<Report, Error> {
+        let mut sinks = (Profile::new(), self.trace);
+        let ended = trace(target, &mut sinks, self.steps)?;
+
+        Ok(Report {
+            program: target.program().to_vec(),
+            profile: sinks.0,
+            ended,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "day18"))]
+mod tests {
+    use super::*;
+    use day18::{self, Duo, Sound};
+    use vm::{Cpu, Operand};
+
+    const PROGRAM: &str = "set a 3
+add b a
+add a -1
+jgz a -2
+snd b
+rcv b";
+
+    #[test]
+    fn test_trace() {
+        let program = day18::parse(PROGRAM).unwrap();
+        let mut cpu = Cpu::new(&program, Sound::default());
+        let mut events = Vec::new();
+
+        assert_eq!(Ok(true), trace(&mut cpu, &mut events, None).map_err(|_| ()));
+        assert_eq!(12, events.len());
+        assert_eq!(
+            Event {
+                program: 0,
+                ip: 1,
+                instruction: Instruction::Add(1, Operand::Reg(0)),
+                changes: vec![Change {
+                    register: 1,
+                    old: 0,
+                    new: 3,
+                }],
+                next: Some(2),
+            },
+            events[1]
+        );
+        assert_eq!(None, events[11].next);
+    }
+
+    impl TraceSink for Vec<Event> {
+        fn record(&mut self, event: &Event) -> Result<(), Error> {
+            self.push(event.clone());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_trace_until_recovered() {
+        // Once it recovered a frequency, the program would loop on its rcv forever
+        let program = day18::parse("snd 4\nset a 1\nrcv a\njgz a -1").unwrap();
+        let mut cpu = Cpu::new(&program, Sound::default());
+        let mut profile = Profile::new();
+
+        assert_eq!(Ok(true), trace(&mut cpu, &mut profile, None).map_err(|_| ()));
+        assert_eq!(3, profile.total());
+        assert_eq!(Some(4), cpu.isa.recovered);
+    }
+
+    #[test]
+    fn test_trace_duo() {
+        let program = day18::parse("snd p\nrcv a\nrcv a").unwrap();
+        let mut duo = Duo::new(&program);
+        let mut events = Vec::new();
+
+        assert_eq!(Ok(false), trace(&mut duo, &mut events, Some(1)).map_err(|_| ()));
+        assert_eq!(Ok(true), trace(&mut duo, &mut events, None).map_err(|_| ()));
+        // The last rcv of each program never runs
+        let runs: Vec<_> = events.iter().map(|e| (e.program, e.ip)).collect();
+        assert_eq!(vec![(0, 0), (1, 0), (0, 1), (1, 1)], runs);
+        assert_eq!("a:0->1", events[2].changes[0].to_string());
+    }
+
+    #[test]
+    fn test_profile() {
+        let program = day18::parse(PROGRAM).unwrap();
+        let mut cpu = Cpu::new(&program, Sound::default());
+        let mut sinks = (Profile::new(), Some(CsvTrace::new(Vec::new())));
+
+        trace(&mut cpu, &mut sinks, None).unwrap();
+        let (profile, csv) = sinks;
+
+        assert_eq!(12, profile.total());
+        assert_eq!(
+            vec![Loop {
+                start: 1,
+                end: 3,
+                iterations: 2,
+                executed: 9,
+            }],
+            profile.hot_loops()
+        );
+
+        let csv = String::from_utf8(csv.unwrap().output).unwrap();
+        assert!(csv.starts_with("program,ip,instruction,changes,next\n0,0,set a 3,a:0->3,1\n"));
+        assert!(csv.ends_with("0,5,rcv b,,\n"));
+
+        let mut counts = Vec::new();
+        profile.write_csv(&program, &mut counts).unwrap();
+        assert_eq!(
+            "ip,instruction,count\n0,set a 3,1\n1,add b a,3\n2,add a -1,3\n3,jgz a -2,3\n\
+             4,snd b,1\n5,rcv b,1\n",
+            String::from_utf8(counts).unwrap()
+        );
+    }
+}
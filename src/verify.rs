@@ -0,0 +1,164 @@
+use failure::Error;
+use registry::Part;
+use runner::Answer;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use toml::Value;
+
+/// Known-good answer of each part of each day.
+pub type Answers = BTreeMap<(u8, Part), String>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String, actual: String },
+    Error(String),
+    /// The part ran fine but `answers.toml` has nothing to compare it with.
+    Unknown(String),
+}
+
+/// Path of the answers file shipped with the repository.
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.toml")
+}
+
+pub fn read<P: AsRef<Path>>(path: P) -> Result<Answers, Error> {
+    let path = path.as_ref();
+    let mut answers = String::new();
+    File::open(path)
+        .and_then(|mut file| file.read_to_string(&mut answers))
+        .map_err(|err| format_err!("{}: {}", path.display(), err))?;
+
+    parse(&answers).map_err(|err| format_err!("{}: {}", path.display(), err))
+}
+
+/// Parses a TOML table of days, each one being a table of parts:
+///
+/// ```toml
+/// [16]
+/// a = "giadhmkpcnbfjelo"
+/// b = "njfgilbkcoemhpad"
+/// ```
+pub fn parse(input: &str) -> Result<Answers, Error> {
+    let value: Value = input.parse()?;
+    let days = value
+        .as_table()
+        .ok_or_else(|| format_err!("Expected a table of days"))?;
+
+    let mut answers = Answers::new();
+
+    for (day, parts) in days {
+        let day: u8 = day.parse()
+            .map_err(|_| format_err!("Invalid day: {:?}", day))?;
+        let parts = parts
+            .as_table()
+            .ok_or_else(|| format_err!("Day {} is not a table of parts", day))?;
+
+        for (part, expected) in parts {
+            let part: Part = part.parse()?;
+            let expected = match *expected {
+                Value::String(ref expected) => expected.clone(),
+                Value::Integer(expected) => expected.to_string(),
+                _ => {
+                    return Err(format_err!(
+                        "Day {} part {} is neither a string nor an integer",
+                        day,
+                        part
+                    ))
+                }
+            };
+
+            answers.insert((day, part), expected);
+        }
+    }
+
+    Ok(answers)
+}
+
+pub fn check(answer: &Answer, answers: &Answers) -> Verdict {
+    match (&answer.answer, answers.get(&(answer.day, answer.part))) {
+        (&Err(ref err), _) => Verdict::Error(err.clone()),
+        (&Ok(ref actual), None) => Verdict::Unknown(actual.clone()),
+        (&Ok(ref actual), Some(expected)) if actual == expected => Verdict::Pass,
+        (&Ok(ref actual), Some(expected)) => Verdict::Fail {
+            expected: expected.clone(),
+            actual: actual.clone(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use registry;
+    use std::time::Duration;
+
+    fn answer(day: u8, part: Part, answer: Result<&str, &str>) -> Answer {
+        Answer {
+            day,
+            part,
+            answer: answer.map(String::from).map_err(String::from),
+            elapsed: Duration::new(0, 0),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let answers = parse(
+            "[1]
+a = \"3\"
+b = 6
+
+[25]
+a = \"2725\"",
+        ).unwrap();
+
+        assert_eq!(3, answers.len());
+        assert_eq!("3", answers[&(1, Part::A)]);
+        assert_eq!("6", answers[&(1, Part::B)]);
+        assert_eq!("2725", answers[&(25, Part::A)]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("[x]\na = \"1\"").is_err());
+        assert!(parse("[1]\nc = \"1\"").is_err());
+        assert!(parse("[1]\na = 1.5").is_err());
+        assert!(parse("1 = \"1\"").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = parse("[1]\na = \"3\"").unwrap();
+
+        assert_eq!(Verdict::Pass, check(&answer(1, Part::A, Ok("3")), &answers));
+        assert_eq!(
+            Verdict::Fail {
+                expected: String::from("3"),
+                actual: String::from("4"),
+            },
+            check(&answer(1, Part::A, Ok("4")), &answers)
+        );
+        assert_eq!(
+            Verdict::Error(String::from("oops")),
+            check(&answer(1, Part::A, Err("oops")), &answers)
+        );
+        assert_eq!(
+            Verdict::Unknown(String::from("6")),
+            check(&answer(1, Part::B, Ok("6")), &answers)
+        );
+    }
+
+    #[test]
+    fn test_every_part_has_an_answer() {
+        let answers = read(default_path()).unwrap();
+
+        for day in registry::all() {
+            for &part in day.parts {
+                assert!(answers.contains_key(&(day.day, part)), "day {} {}", day.day, part);
+            }
+        }
+    }
+}
@@ -0,0 +1,474 @@
+//! Interpreter of the assembly of days 18 and 23, whose dialects share the arithmetic and jump
+//! instructions and differ in the rest.
+
+use error::Error;
+use parser;
+use std::fmt;
+
+/// A register, as its index from `a`.
+pub type Register = u8;
+
+/// Name of the register `register`.
+pub fn register_name(register: Register) -> char {
+    char::from(b'a' + register)
+}
+
+/// Register named `name`, from `a`.
+pub fn register(name: char) -> Register {
+    (name as u8).wrapping_sub(b'a')
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Reg(Register),
+    Imm(i64),
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Operand::Reg(r) => write!(f, "{}", register_name(r)),
+            Operand::Imm(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// An instruction of any dialect, decoded once by `assemble`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Set(Register, Operand),
+    Add(Register, Operand),
+    Sub(Register, Operand),
+    Mul(Register, Operand),
+    Mod(Register, Operand),
+    Jgz(Operand, Operand),
+    Jnz(Operand, Operand),
+    Snd(Operand),
+    Rcv(Register),
+}
+
+impl Instruction {
+    pub fn opcode(&self) -> &'static str {
+        match *self {
+            Instruction::Set(..) => "set",
+            Instruction::Add(..) => "add",
+            Instruction::Sub(..) => "sub",
+            Instruction::Mul(..) => "mul",
+            Instruction::Mod(..) => "mod",
+            Instruction::Jgz(..) => "jgz",
+            Instruction::Jnz(..) => "jnz",
+            Instruction::Snd(..) => "snd",
+            Instruction::Rcv(..) => "rcv",
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Instruction::Set(r, ref v)
+            | Instruction::Add(r, ref v)
+            | Instruction::Sub(r, ref v)
+            | Instruction::Mul(r, ref v)
+            | Instruction::Mod(r, ref v) => {
+                write!(f, "{} {} {}", self.opcode(), register_name(r), v)
+            }
+            Instruction::Jgz(ref x, ref y) | Instruction::Jnz(ref x, ref y) => {
+                write!(f, "{} {} {}", self.opcode(), x, y)
+            }
+            Instruction::Snd(ref x) => write!(f, "{} {}", self.opcode(), x),
+            Instruction::Rcv(r) => write!(f, "{} {}", self.opcode(), register_name(r)),
+        }
+    }
+}
+
+/// Where to go once an instruction has run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flow {
+    Next,
+    /// Offset from the current instruction.
+    Jump(i64),
+    /// The instruction cannot run yet, and is run again on the next step.
+    Wait,
+}
+
+/// An assembly dialect, run by a `Cpu`.
+///
+/// Besides its own instructions, a dialect can use the common ones: `set`, `add`, `sub`,
+/// `mul`, `mod`, `jgz` and `jnz`, by listing them in `OPCODES`.
+pub trait InstructionSet {
+    /// Day of the puzzle, which errors point at.
+    const DAY: u8;
+
+    /// Number of registers, named from `a`.
+    const REGISTERS: u8;
+
+    /// Instructions of the dialect, which `assemble` accepts.
+    const OPCODES: &'static [&'static str];
+
+    /// Runs `instruction` if it is one of the dialect, or returns `None` to let the common
+    /// instruction run.
+    fn execute(&mut self, registers: &mut Registers, instruction: &Instruction) -> Option<Flow>;
+
+    /// Whether the program found what it is run for, such as a recovered frequency, and need
+    /// not go on even though it could.
+    fn finished(&self) -> bool {
+        false
+    }
+}
+
+/// Decodes each line in an `Instruction`, checking it is one of `I` and that its registers
+/// exist.
+pub fn assemble<I: InstructionSet>(input: &str) -> Result<Vec<Instruction>, Error> {
+    parser::lines(I::DAY, input)
+        .map(|line| {
+            let mut words = line.words();
+            let op = words.token("an instruction")?;
+
+            if !I::OPCODES.contains(&op) {
+                return Err(line.error(op, format!("Unknown instruction: {}", op)));
+            }
+
+            let operand_count = match op {
+                "snd" | "rcv" => 1,
+                _ => 2,
+            };
+            let operands: Vec<_> = words.collect();
+            if operands.len() != operand_count {
+                return Err(line.error(op, format!("{} expects {} operand(s)", op, operand_count)));
+            }
+
+            let register = |operand: &str| match decode::<I>(operand) {
+                Some(Operand::Reg(r)) => Ok(r),
+                _ => Err(line.error(operand, format!("Expected a register: {:?}", operand))),
+            };
+            let value = |operand: &str| {
+                decode::<I>(operand).ok_or_else(|| {
+                    line.error(
+                        operand,
+                        format!("Expected a register or an integer: {:?}", operand),
+                    )
+                })
+            };
+
+            Ok(match op {
+                "set" => Instruction::Set(register(operands[0])?, value(operands[1])?),
+                "add" => Instruction::Add(register(operands[0])?, value(operands[1])?),
+                "sub" => Instruction::Sub(register(operands[0])?, value(operands[1])?),
+                "mul" => Instruction::Mul(register(operands[0])?, value(operands[1])?),
+                "mod" => Instruction::Mod(register(operands[0])?, value(operands[1])?),
+                "jgz" => Instruction::Jgz(value(operands[0])?, value(operands[1])?),
+                "jnz" => Instruction::Jnz(value(operands[0])?, value(operands[1])?),
+                "snd" => Instruction::Snd(value(operands[0])?),
+                "rcv" => Instruction::Rcv(register(operands[0])?),
+                _ => return Err(line.error(op, format!("Unknown instruction: {}", op))),
+            })
+        })
+        .collect()
+}
+
+/// A register of `I`, or an integer.
+fn decode<I: InstructionSet>(operand: &str) -> Option<Operand> {
+    let bytes = operand.as_bytes();
+
+    if bytes.len() == 1 && bytes[0] >= b'a' && bytes[0] < b'a' + I::REGISTERS {
+        return Some(Operand::Reg(bytes[0] - b'a'));
+    }
+
+    operand.parse().ok().map(Operand::Imm)
+}
+
+/// Registers of a `Cpu`, all starting at 0.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Registers {
+    values: Vec<i64>,
+}
+
+impl Clone for Registers {
+    fn clone(&self) -> Registers {
+        Registers {
+            values: self.values.clone(),
+        }
+    }
+
+    /// Copies the values of `source` without allocating, as done after each step.
+    fn clone_from(&mut self, source: &Registers) {
+        self.values.clone_from(&source.values);
+    }
+}
+
+impl Registers {
+    pub fn new(count: u8) -> Registers {
+        Registers {
+            values: vec![0; usize::from(count)],
+        }
+    }
+
+    pub fn get(&self, register: Register) -> i64 {
+        self.values[usize::from(register)]
+    }
+
+    pub fn set(&mut self, register: Register, value: i64) {
+        self.values[usize::from(register)] = value;
+    }
+
+    pub fn eval(&self, operand: Operand) -> i64 {
+        match operand {
+            Operand::Reg(r) => self.get(r),
+            Operand::Imm(value) => value,
+        }
+    }
+
+    /// Values of the registers, from `a`.
+    pub fn values(&self) -> &[i64] {
+        &self.values
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    Running,
+    /// The last instruction has to be run again, as for a `rcv` without anything to receive.
+    Waiting,
+    /// The instruction pointer left the program.
+    Halted,
+}
+
+/// Runs a program of the dialect `I`, whose state it owns.
+pub struct Cpu<'a, I> {
+    pub ip: usize,
+    pub state: State,
+    pub registers: Registers,
+    pub isa: I,
+    program: &'a [Instruction],
+}
+
+impl<'a, I: InstructionSet> Cpu<'a, I> {
+    pub fn new(program: &'a [Instruction], isa: I) -> Cpu<'a, I> {
+        Cpu {
+            ip: 0,
+            state: if program.is_empty() {
+                State::Halted
+            } else {
+                State::Running
+            },
+            registers: Registers::new(I::REGISTERS),
+            isa,
+            program,
+        }
+    }
+
+    pub fn program(&self) -> &'a [Instruction] {
+        self.program
+    }
+
+    /// Runs the current instruction, doing nothing once halted.
+    pub fn step(&mut self) -> Result<State, Error> {
+        if self.state == State::Halted {
+            return Ok(State::Halted);
+        }
+
+        let program = self.program;
+        let instruction = &program[self.ip];
+        let flow = match self.isa.execute(&mut self.registers, instruction) {
+            Some(flow) => flow,
+            None => self.execute_common(instruction)?,
+        };
+
+        let ip = match flow {
+            Flow::Next => self.ip as i64 + 1,
+            Flow::Jump(offset) => (self.ip as i64).checked_add(offset).ok_or_else(|| {
+                Error::runtime(I::DAY, format!("Jump overflow: {} by {}", self.ip, offset))
+            })?,
+            Flow::Wait => {
+                self.state = State::Waiting;
+                return Ok(self.state);
+            }
+        };
+
+        if ip < 0 || ip >= self.program.len() as i64 {
+            self.state = State::Halted;
+        } else {
+            self.ip = ip as usize;
+            self.state = State::Running;
+        }
+
+        Ok(self.state)
+    }
+
+    /// Steps until the program halts or waits.
+    pub fn run(&mut self) -> Result<State, Error> {
+        while self.step()? == State::Running {}
+
+        Ok(self.state)
+    }
+
+    fn execute_common(&mut self, instruction: &Instruction) -> Result<Flow, Error> {
+        let ip = self.ip;
+        let registers = &mut self.registers;
+        let overflow = || Error::runtime(I::DAY, format!("Overflow at {}: {}", ip, instruction));
+
+        match *instruction {
+            Instruction::Set(r, v) => {
+                let v = registers.eval(v);
+                registers.set(r, v);
+            }
+            Instruction::Add(r, v) => {
+                let v = registers.get(r).checked_add(registers.eval(v)).ok_or_else(overflow)?;
+                registers.set(r, v);
+            }
+            Instruction::Sub(r, v) => {
+                let v = registers.get(r).checked_sub(registers.eval(v)).ok_or_else(overflow)?;
+                registers.set(r, v);
+            }
+            Instruction::Mul(r, v) => {
+                let v = registers.get(r).checked_mul(registers.eval(v)).ok_or_else(overflow)?;
+                registers.set(r, v);
+            }
+            Instruction::Mod(r, v) => {
+                let v = registers.eval(v);
+                if v == 0 {
+                    return Err(Error::runtime(
+                        I::DAY,
+                        format!("Division by zero at {}: {}", ip, instruction),
+                    ));
+                }
+                // Only `i64::MIN % -1` wraps, to its remainder of 0
+                let v = registers.get(r).wrapping_rem(v);
+                registers.set(r, v);
+            }
+            Instruction::Jgz(x, y) => if registers.eval(x) > 0 {
+                return Ok(Flow::Jump(registers.eval(y)));
+            },
+            Instruction::Jnz(x, y) => if registers.eval(x) != 0 {
+                return Ok(Flow::Jump(registers.eval(y)));
+            },
+            Instruction::Snd(..) | Instruction::Rcv(..) => {
+                return Err(Error::runtime(
+                    I::DAY,
+                    format!("Unsupported instruction: {}", instruction),
+                ))
+            }
+        }
+
+        Ok(Flow::Next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The common instructions, and `snd` which keeps the values it is given.
+    #[derive(Debug, Default)]
+    struct Common {
+        out: Vec<i64>,
+    }
+
+    impl InstructionSet for Common {
+        const DAY: u8 = 18;
+        const REGISTERS: u8 = 4;
+        const OPCODES: &'static [&'static str] =
+            &["snd", "set", "add", "sub", "mul", "mod", "jgz", "jnz"];
+
+        fn execute(
+            &mut self,
+            registers: &mut Registers,
+            instruction: &Instruction,
+        ) -> Option<Flow> {
+            match *instruction {
+                Instruction::Snd(x) => {
+                    self.out.push(registers.eval(x));
+                    Some(Flow::Next)
+                }
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let program = assemble::<Common>(
+            "set a 5
+add b a
+mul b 3
+sub a 1
+mod b 7
+snd b
+jnz a -5
+jgz 1 -10",
+        ).unwrap();
+        let mut cpu = Cpu::new(&program, Common::default());
+
+        assert_eq!(Ok(State::Halted), cpu.run());
+        assert_eq!(vec![1, 1, 5, 0, 3], cpu.isa.out);
+        assert_eq!(&[0, 3, 0, 0], cpu.registers.values());
+        assert_eq!(Ok(State::Halted), cpu.step());
+    }
+
+    #[test]
+    fn test_overflow() {
+        let program = assemble::<Common>("set a -9223372036854775808\nmod a -1\nsnd a").unwrap();
+        let mut cpu = Cpu::new(&program, Common::default());
+        assert_eq!(Ok(State::Halted), cpu.run());
+        assert_eq!(vec![0], cpu.isa.out);
+
+        for program in &[
+            "set a 9223372036854775807\nadd a 1",
+            "set a -9223372036854775808\nsub a 1",
+            "set a 4611686018427387904\nmul a 2",
+        ] {
+            let program = assemble::<Common>(program).unwrap();
+            let mut cpu = Cpu::new(&program, Common::default());
+            assert!(cpu.run().is_err());
+        }
+
+        let program = assemble::<Common>("set a 9223372036854775807\nmul a a").unwrap();
+        let mut cpu = Cpu::new(&program, Common::default());
+        assert_eq!(Err(Error::runtime(18, "Overflow at 1: mul a a")), cpu.run());
+
+        let program = assemble::<Common>("set a 1\njgz a 9223372036854775807").unwrap();
+        let mut cpu = Cpu::new(&program, Common::default());
+        assert_eq!(
+            Err(Error::runtime(18, "Jump overflow: 1 by 9223372036854775807")),
+            cpu.run()
+        );
+    }
+
+    #[test]
+    fn test_mod_zero() {
+        let program = assemble::<Common>("set a 5\nmod a b").unwrap();
+        let mut cpu = Cpu::new(&program, Common::default());
+
+        assert_eq!(Err(Error::runtime(18, "Division by zero at 1: mod a b")), cpu.run());
+        assert_eq!(&[5, 0, 0, 0], cpu.registers.values());
+    }
+
+    #[test]
+    fn test_assemble() {
+        assert_eq!(
+            Ok(vec![
+                Instruction::Set(0, Operand::Imm(-5)),
+                Instruction::Jgz(Operand::Reg(3), Operand::Reg(1)),
+                Instruction::Snd(Operand::Imm(2)),
+            ]),
+            assemble::<Common>("set a -5\njgz d b\nsnd 2")
+        );
+        assert_eq!("jgz d -1", Instruction::Jgz(Operand::Reg(3), Operand::Imm(-1)).to_string());
+    }
+
+    #[test]
+    fn test_assemble_errors() {
+        assert_eq!(
+            Err(Error::parse(18, 1, 5, "Expected a register: \"e\"").with_source("set e 1")),
+            assemble::<Common>("set e 1")
+        );
+        assert_eq!(
+            Err(Error::parse(18, 2, 1, "Unknown instruction: rcv").with_source("rcv a")),
+            assemble::<Common>("snd 1\nrcv a")
+        );
+        assert!(assemble::<Common>("jnz a").is_err());
+        assert!(assemble::<Common>("snd x1").is_err());
+        assert!(assemble::<Common>("set a 1 2").is_err());
+    }
+}